@@ -0,0 +1,116 @@
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak { AsDealt, HighestFirst } // Which card order to compare
+
+#[derive(Debug, Clone)]
+pub struct Rules {
+    pub hand_size: usize,
+    pub ranks: Vec<char>,            // Weakest first
+    pub wilds: Vec<char>,            // Each joins the biggest group
+    pub categories: Vec<Vec<usize>>, // Weakest first, leading group sizes
+    pub tie_break: TieBreak
+}
+
+// Category, then the ranks of the cards in tie break order
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Strength { pub category: usize, pub ranks: Vec<usize> }
+
+impl Rules {
+
+    pub fn camel_cards(ranks: &str, wilds: &str) -> Self {
+
+        let categories = [&[1][..], &[2], &[2, 2], &[3], &[3, 2], &[4], &[5]];
+
+        Self { hand_size: 5,
+               ranks: ranks.chars().collect(),
+               wilds: wilds.chars().collect(),
+               categories: categories.iter().map(|c| c.to_vec()).collect(),
+               tie_break: TieBreak::AsDealt }
+    }
+
+    pub fn rank(&self, card: char) -> Option<usize> {
+
+        self.ranks.iter().position(|&c| c == card)
+    }
+
+    pub fn strength(&self, hand: &str) -> Option<Strength> { // None if bad
+
+        let mut ranks = hand.chars()
+                            .map(|c| self.rank(c))
+                            .collect::<Option<Vec<_>>>()?;
+
+        if ranks.len() != self.hand_size { return None; }
+
+        let mut counts = vec![0; self.ranks.len()];
+
+        let mut wild_count = 0;
+
+        for card in hand.chars() {
+
+            if self.wilds.contains(&card) { wild_count += 1; }
+            else { counts[self.rank(card)?] += 1; }
+        }
+
+        counts.sort_by(|a, b| b.cmp(a));
+
+        counts[0] += wild_count;
+
+        let category = self.categories
+                           .iter()
+                           .rposition(|c| counts.starts_with(c))?;
+
+        if self.tie_break == TieBreak::HighestFirst {
+
+            ranks.sort_by(|a, b| b.cmp(a));
+        }
+
+        Some(Strength { category, ranks })
+    }
+
+    pub fn total_winnings(&self, input: &str) -> usize {
+
+        let mut hand_bids =
+            input.split('\n')
+                 .map(|l| l.split_once(' ').unwrap())
+                 .map(|(h, b)| (self.strength(h).unwrap(),
+                                b.parse::<usize>().unwrap()))
+                 .collect::<Vec<_>>();
+
+        hand_bids.sort();
+
+        hand_bids.iter()
+                 .enumerate()
+                 .map(|(index, (_, bid))| (index + 1) * bid)
+                 .sum()
+    }
+}
+
+#[test]
+fn test_rules() {
+
+    let plain = Rules::camel_cards("23456789TJQKA", "");
+
+    let strength = |rules: &Rules, hand| rules.strength(hand).unwrap();
+
+    assert_eq!(strength(&plain, "KTJJT").category, 2);
+
+    assert!(strength(&plain, "33332") > strength(&plain, "2AAAA"));
+
+    let jokers = Rules::camel_cards("J23456789TQKA", "J");
+
+    assert_eq!(strength(&jokers, "KTJJT").category, 5);
+
+    assert_eq!(strength(&jokers, "JJJJJ").category, 6);
+
+    let two_jokers = Rules::camel_cards("*J23456789TQKA", "*J");
+
+    assert_eq!(strength(&two_jokers, "*J2J3").category, 5);
+
+    let poker = Rules { tie_break: TieBreak::HighestFirst, ..plain.clone() };
+
+    assert!(strength(&poker, "2AAAA") > strength(&poker, "33332"));
+
+    assert_eq!(plain.strength("2345"), None);
+
+    assert_eq!(plain.strength("2345X"), None);
+}
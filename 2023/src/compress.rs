@@ -0,0 +1,183 @@
+
+use super::grid::{ *, Direction::* };
+
+use std::ops::Range;
+
+pub struct Axis { breaks: Vec<isize> } // Cell i spans breaks[i] .. breaks[i + 1]
+
+impl Axis {
+
+    pub fn new(breaks: impl IntoIterator<Item = isize>) -> Self {
+
+        let mut breaks = breaks.into_iter().collect::<Vec<_>>();
+
+        breaks.sort();
+        breaks.dedup();
+
+        Self { breaks }
+    }
+
+    pub fn len(&self) -> usize { self.breaks.len().saturating_sub(1) }
+
+    pub fn index_of(&self, value: isize) -> Option<usize> {
+
+        self.breaks
+            .partition_point(|&b| b <= value)
+            .checked_sub(1)
+            .filter(|&i| i < self.len())
+    }
+
+    pub fn within(&self, range: Range<isize>) -> Range<usize> {
+
+        let start = self.breaks.partition_point(|&b| b < range.start);
+
+        let end = self.breaks.partition_point(|&b| b <= range.end);
+
+        start .. end.saturating_sub(1).max(start)
+    }
+
+    pub fn span(&self, index: usize) -> Range<isize> {
+
+        self.breaks[index] .. self.breaks[index + 1]
+    }
+
+    pub fn weight(&self, index: usize) -> usize {
+
+        self.span(index).len()
+    }
+}
+
+pub struct Compressed<T> { pub xs: Axis, pub ys: Axis, pub grid: Grid<T> }
+
+impl<T> Compressed<T> {
+
+    pub fn new(xs: Axis, ys: Axis, value: T) -> Self where T: Clone {
+
+        let grid = Grid::new(xs.len(), ys.len(), value);
+
+        Self { xs, ys, grid }
+    }
+
+    pub fn coord_of(&self, x: isize, y: isize) -> Option<Coord> {
+
+        Some(Coord::new(self.xs.index_of(x)?, self.ys.index_of(y)?))
+    }
+
+    pub fn weight(&self, coord: Coord) -> usize {
+
+        self.xs.weight(coord.x) * self.ys.weight(coord.y)
+    }
+
+    pub fn fill(&mut self, xs: Range<isize>, ys: Range<isize>, value: T)
+        where T: Clone {
+
+        for y in self.ys.within(ys) {
+
+            for x in self.xs.within(xs.clone()) {
+
+                *self.grid.get_at_mut(Some(Coord::new(x, y))).unwrap() =
+                    value.clone();
+            }
+        }
+    }
+
+    pub fn area(&self, include: impl Fn(Coord, &T) -> bool) -> usize {
+
+        self.grid.iter()
+                 .filter(|&(c, v)| include(c, v))
+                 .map(|(c, _)| self.weight(c))
+                 .sum()
+    }
+
+    pub fn flood_fill(&self, start: Coord, passable: impl Fn(&T) -> bool)
+        -> Grid<bool> {
+
+        let mut filled = Grid::new(self.grid.width(), self.grid.height(), false);
+
+        let mut todo = vec![start];
+
+        while let Some(coord) = todo.pop() {
+
+            if !self.grid.get_at(Some(coord)).is_some_and(&passable) { continue; }
+
+            match filled.get_at_mut(Some(coord)) { Some(true) => continue,
+                                                   Some(f) => *f = true,
+                                                   None => continue }
+
+            todo.extend([Up, Down, Left, Right].into_iter()
+                                               .filter_map(|d| coord + d));
+        }
+
+        filled
+    }
+
+    pub fn distance(&self, a: Coord, b: Coord) -> usize { // Between origins
+
+        self.xs.span(a.x).start.abs_diff(self.xs.span(b.x).start)
+        + self.ys.span(a.y).start.abs_diff(self.ys.span(b.y).start)
+    }
+}
+
+#[test]
+fn test_compressed() {
+
+    let xs = Axis::new([0, 2, 1_000_000, 1_000_002]);
+
+    let mut compressed = Compressed::new(xs, Axis::new([0, 5, 10]), false);
+
+    compressed.fill(2 .. 1_000_000, 0 .. 10, true);
+
+    assert_eq!(compressed.area(|_, &v| v), 999_998 * 10);
+
+    let filled = compressed.flood_fill(Coord::new(0, 0), |&v| !v);
+
+    assert_eq!(compressed.area(|c, _| *filled.get_at(Some(c)).unwrap()), 20);
+
+    let far = compressed.coord_of(1_000_000, 7).unwrap();
+
+    assert_eq!(compressed.distance(Coord::new(0, 0), far), 1_000_005);
+}
+
+#[test]
+fn test_trench_against_polygon() {
+
+    use super::geometry::Polygon;
+
+    let moves = [(Right, 1_000_000), (Down, 5), (Left, 999_998), (Down, 3),
+                 (Left, 2), (Up, 8)];
+
+    let mut corners = vec![(0isize, 0isize)];
+
+    for (direction, distance) in moves {
+
+        let ((x, y), (dx, dy)) = (*corners.last().unwrap(),
+                                  direction.to_offset());
+
+        corners.push((x + dx * distance, y + dy * distance));
+    }
+
+    // Each trench cube gets its own column and row, with a margin around
+    let breaks = |v| [v - 1, v, v + 1, v + 2];
+
+    let mut lagoon =
+        Compressed::new(Axis::new(corners.iter().flat_map(|c| breaks(c.0))),
+                        Axis::new(corners.iter().flat_map(|c| breaks(c.1))),
+                        false);
+
+    for pair in corners.windows(2) {
+
+        let ((ax, ay), (bx, by)) = (pair[0], pair[1]);
+
+        lagoon.fill(ax.min(bx) .. ax.max(bx) + 1,
+                    ay.min(by) .. ay.max(by) + 1,
+                    true);
+    }
+
+    let outside = lagoon.flood_fill(Coord::new(0, 0), |&trench| !trench);
+
+    let filled = lagoon.area(|c, _| !outside.get_at(Some(c)).unwrap());
+
+    let moves = moves.map(|(d, n)| (d, n as usize));
+
+    assert_eq!(filled as u64, Polygon::from_moves(moves).lattice_points());
+}
@@ -0,0 +1,67 @@
+
+use std::{ collections::HashMap, hash::Hash };
+
+pub struct Cycle<S> { pub prefix: usize, pub period: usize, states: Vec<S> }
+
+impl<S> Cycle<S> {
+
+    pub fn state_at(&self, step: usize) -> &S {
+
+        if step < self.states.len() { return &self.states[step]; }
+
+        &self.states[self.prefix + (step - self.prefix) % self.period]
+    }
+
+    pub fn value_at<T>(&self, step: usize, value: impl Fn(&S) -> T) -> T {
+
+        value(self.state_at(step))
+    }
+}
+
+// States with equal keys are compared in full, so the key can be a hash
+pub fn find_cycle<S: Eq, K: Hash + Eq>(initial: S,
+                                       mut step: impl FnMut(&S) -> S,
+                                       key: impl Fn(&S) -> K) -> Cycle<S> {
+
+    let mut key_to_steps = HashMap::<K, Vec<usize>>::new();
+
+    let mut states = vec![initial];
+
+    loop {
+
+        let state = states.last().unwrap();
+
+        let seen = key_to_steps.entry(key(state)).or_default();
+
+        let repeat = seen.iter().find(|&&s| &states[s] == state);
+
+        if let Some(&prefix) = repeat {
+
+            let period = states.len() - 1 - prefix;
+
+            states.pop();
+
+            return Cycle { prefix, period, states };
+        }
+
+        seen.push(states.len() - 1);
+
+        let next = step(state);
+
+        states.push(next);
+    }
+}
+
+#[test]
+fn test_find_cycle() {
+
+    let cycle = find_cycle(0, |&n| (n * n + 1) % 11, |&n| n % 3);
+
+    assert_eq!((cycle.prefix, cycle.period), (4, 2)); // 0, 1, 2, 5, then 4, 6
+
+    assert_eq!(*cycle.state_at(3), 5);
+
+    assert_eq!(*cycle.state_at(1_000_000_001), 6);
+
+    assert_eq!(cycle.value_at(1_000_000_000, |&n| n * 10), 40);
+}
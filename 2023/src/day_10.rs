@@ -1,35 +1,11 @@
 
 const INPUT: &str = include_str!("../input/day_10.txt");
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
-enum Direction { North, South, East, West }
-
-use Direction::*;
+use super::{ geometry::*, grid::{ Coord, Direction, Direction::* } };
 
 #[derive(Clone, Copy)]
 struct Pipe { connects: [Direction; 2] }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-struct Coord { x: usize, y: usize }
-
-impl Coord {
-
-    fn offset(&self, direction: Direction) -> Option<Coord> {
-
-        let (x, y) = match direction { North => (0, -1),
-                                       East  => (1, 0),
-                                       South => (0, 1),
-                                       West  => (-1, 0) };
-
-        match (self.x.checked_add_signed(x), self.y.checked_add_signed(y)) {
-
-            (Some(x), Some(y)) => Some(Coord { x, y }),
-
-            _ => None
-        }
-    }
-}
-
 struct Grid { start: Coord, cells: Vec<Vec<Option<Pipe>>> }
 
 impl Grid {
@@ -44,12 +20,12 @@ fn parse_pipe(char: char) -> Option<Pipe> {
 
     if char == '.' || char == 'S' { return None; }
 
-    let connects = match char { '|' => [North, South],
-                                '-' => [East,  West],
-                                'L' => [North, East],
-                                'J' => [North, West],
-                                '7' => [South, West],
-                                'F' => [South, East],
+    let connects = match char { '|' => [Up,   Down],
+                                '-' => [Left, Right],
+                                'L' => [Up,   Right],
+                                'J' => [Up,   Left],
+                                '7' => [Down, Left],
+                                'F' => [Down, Right],
                                 _ => panic!("Bad pipe char") };
     Some(Pipe { connects })
 }
@@ -58,22 +34,16 @@ fn infer_connects(grid: &Grid, coord: Coord) -> [Direction; 2] {
 
     let mut connects = Vec::new();
 
-    let mut connect_if_has = |direction: Direction, has: Direction| {
+    for direction in Direction::ALL {
         
-        if let Some(pipe) = coord.offset(direction)
-                                 .and_then(|c| grid.get_pipe(c)) {
+        if let Some(pipe) = (coord + direction).and_then(|c| grid.get_pipe(c)) {
         
-            if pipe.connects.contains(&has) {
+            if pipe.connects.contains(&!direction) {
 
                 connects.push(direction);
             }
         }
-    };
-
-    connect_if_has(North, South);
-    connect_if_has(East,  West);
-    connect_if_has(South, North);
-    connect_if_has(West,  East);
+    }
 
     connects.sort();
 
@@ -112,7 +82,7 @@ fn step(grid: &Grid, coord: Coord, last: Coord) -> Coord {
         .unwrap()
         .connects
         .iter()
-        .filter_map(|&d| coord.offset(d))
+        .filter_map(|&d| coord + d)
         .find(|&c| c != last)
         .unwrap()
 }
@@ -170,8 +140,6 @@ mod part_1 {
 
  mod part_2 {
 
-    use std::{collections::BTreeSet, vec};
-
     use super::*;
 
     const EXAMPLE_A: &str = "...........\n\
@@ -211,35 +179,7 @@ mod part_1 {
             if trail.last().unwrap() == &grid.start { break; }
         }
 
-        let visited = BTreeSet::from_iter(trail);
-
-        let mut in_count = 0;
-
-        for y in 0 .. grid.cells.len() {
-
-            let mut out = true;
-
-            let mut primer: Option<Direction> = None;
-
-            for x in 0 .. grid.cells[y].len() {
-
-                let coord = Coord { x, y };
-
-                if visited.contains(&coord) {
-                   
-                    match grid.get_pipe(coord).unwrap().connects {
-                        [North, South] => { out = !out; },
-                        [North, East] => { primer = Some(South); },
-                        [South, East] => { primer = Some(North) },
-                        [d, West] => { if primer == Some(d) { out = !out } },
-                        _ => {}
-                    };
-                }
-                else if !out { in_count += 1; }
-            }
-        }
-
-        in_count
+        Polygon::from_coords(trail).interior_points() as usize
     }
 
     #[test]
@@ -1,5 +1,5 @@
 
-use std::collections::HashMap;
+use super::memo::*;
 
 const INPUT: &str = include_str!("../input/day_12.txt");
 
@@ -14,12 +14,12 @@ fn parse_spec(text: &str) -> Vec<usize> {
     text.split(',').map(|f| f.parse().unwrap()).collect()
 }
 
-#[derive(PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 struct State<'a> { row: &'a str, spec: &'a [usize] }
 
 fn arrangements<'a>(mut row: &'a str,
                     spec: &'a [usize],
-                    cache: &mut HashMap<State<'a>, usize>) -> usize {
+                    memo: &mut Memo<State<'a>, usize>) -> usize {
 
     let left = spec.iter().sum::<usize>();
 
@@ -43,14 +43,8 @@ fn arrangements<'a>(mut row: &'a str,
                 spec: &spec[1 ..]
             };
 
-            count += cache.get(&state).copied().unwrap_or_else(|| {
-
-                let count = arrangements(state.row, state.spec, cache);
-
-                cache.insert(state, count);
-
-                count
-            });
+            count += memo.get_or_insert_with(state, |memo|
+                arrangements(state.row, state.spec, memo));
         }
 
         row = if &row[.. 1] == "#" { break; } else { &row[1 ..] };
@@ -67,7 +61,7 @@ mod part_1 {
 
         input.split('\n')
              .map(|l| l.split(' ').collect::<Vec<_>>())
-             .map(|v| arrangements(v[0], &parse_spec(v[1]), &mut HashMap::new()))
+             .map(|v| arrangements(v[0], &parse_spec(v[1]), &mut Memo::new()))
              .sum()
     }
 
@@ -82,16 +76,16 @@ mod part_2 {
 
     use super::*;
 
-    use std::iter::repeat;
+    use std::iter::repeat_n;
 
     fn get_result(input: &str) -> usize {
 
-        let unfold = |t, s| repeat(t).take(5).collect::<Vec<_>>().join(s);
+        let unfold = |t, s| repeat_n(t, 5).collect::<Vec<_>>().join(s);
 
         input.split('\n')
              .map(|l| l.split(' ').collect::<Vec<_>>())
              .map(|v| (unfold(v[0], "?"), unfold(v[1], ",")))
-             .map(|t| arrangements(&t.0, &parse_spec(&t.1), &mut HashMap::new()))
+             .map(|t| arrangements(&t.0, &parse_spec(&t.1), &mut Memo::new()))
              .sum()
     }
 
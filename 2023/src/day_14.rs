@@ -12,25 +12,53 @@ const EXAMPLE: &str = "O....#....\n\
                        #....###..\n\
                        #OO..#....";
 
-use super::grid::{ *, Direction::* };
+use super::{ cycle::*, grid::{ *, Direction::* } };
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 enum Rock { Round, Square }
 
 use Rock::*;
 
-struct Platform { grid: Grid<Option<Rock>> }
+impl GridCell for Option<Rock> {
+
+    fn from_char(char: char) -> Option<Self> {
+
+        match char { '.' => Some(None),
+                     'O' => Some(Some(Round)),
+                     '#' => Some(Some(Square)),
+                     _   => None }
+    }
+
+    fn to_char(&self) -> char {
+
+        match self { None         => '.',
+                     Some(Round)  => 'O',
+                     Some(Square) => '#' }
+    }
+}
+
+fn to_variant(rock: &Option<Rock>) -> usize {
+
+    match rock { None => 0, Some(Round) => 1, Some(Square) => 2 }
+}
+
+#[derive(Clone)]
+struct Platform { grid: Grid<Option<Rock>>, hash: Zobrist }
+
+impl PartialEq for Platform {
+
+    fn eq(&self, other: &Self) -> bool { self.grid == other.grid }
+}
+
+impl Eq for Platform {}
 
 impl Platform {
 
     fn parse(input: &str) -> Self {
 
-        let to_rock = |c| match c { '.' => Some(None),
-                                    'O' => Some(Some(Rock::Round)),
-                                    '#' => Some(Some(Rock::Square)),
-                                    _   => None };
+        let grid = Grid::parse_cells(input).unwrap();
 
-        Self { grid: Grid::parse(input, to_rock).unwrap() }
+        Self { hash: Zobrist::new(&grid, 3, to_variant), grid }
     }
 
     fn tilt(&mut self, direction: Direction) {
@@ -41,7 +69,7 @@ impl Platform {
             _     => (0, 0)
         }));
 
-        while outer.map_or(false, |c| self.grid.in_bounds(c)) {
+        while outer.is_some_and(|c| self.grid.in_bounds(c)) {
 
             let (mut to_coord, mut from_coord) = (outer, outer + !direction);
 
@@ -50,6 +78,7 @@ impl Platform {
                 match (*to, *from) {
 
                     (None, Some(Round)) => { std::mem::swap(to, from);
+                                             self.roll(from_coord, to_coord);
                                              to_coord += !direction;
                                              from_coord += !direction; },
 
@@ -69,6 +98,17 @@ impl Platform {
         }
     }
 
+    fn roll(&mut self, from: Option<Coord>, to: Option<Coord>) {
+
+        let (round, empty) = (to_variant(&Some(Round)), to_variant(&None));
+
+        if let Some((from, to)) = from.zip(to) {
+
+            self.hash.update(from, round, empty);
+            self.hash.update(to, empty, round);
+        }
+    }
+
     fn total_north_load(&self) -> usize {
 
         self.grid.iter()
@@ -93,6 +133,31 @@ mod part_1 {
 
     #[test]
     fn example() { assert_eq!(get_result(EXAMPLE), 136); }
+
+    #[test]
+    fn example_round_trip() {
+
+        assert_eq!(Platform::parse(EXAMPLE).grid.to_string(), EXAMPLE);
+    }
+
+    #[test]
+    fn example_tilted() {
+
+        let mut platform = Platform::parse(EXAMPLE);
+
+        platform.tilt(Up);
+
+        assert_eq!(platform.grid.to_string(), "OOOO.#.O..\n\
+                                               OO..#....#\n\
+                                               OO..O##..O\n\
+                                               O..#.OO...\n\
+                                               ........#.\n\
+                                               ..#....#.#\n\
+                                               ..O..#.O.O\n\
+                                               ..O.......\n\
+                                               #....###..\n\
+                                               #....#....");
+    }
     
     #[test]
     fn real() { assert_eq!(get_result(INPUT), 106990); }
@@ -102,8 +167,6 @@ mod part_2 {
 
     use super::*;
 
-    use std::collections::BTreeMap;
-
     fn cycle_platform(platform: &mut Platform) {
 
         for direction in [Up, Left, Down, Right] {
@@ -112,47 +175,77 @@ mod part_2 {
         }
     }
 
-    fn cycle_period(platform: &mut Platform) -> (usize, usize) {
-
-        let mut hash_to_cycle = BTreeMap::new();
+    fn get_result(input: &str) -> usize {
 
-        let mut cycle = 0;
+        let cycle = find_cycle(Platform::parse(input),
+                               |p| { let mut p = p.clone();
+                                     cycle_platform(&mut p);
+                                     p },
+                               |p| p.hash.hash());
 
-        loop {
+        cycle.value_at(1_000_000_000, Platform::total_north_load)
+    }
 
-            let hash = platform.grid.get_hash();
+    #[test]
+    fn example() { assert_eq!(get_result(EXAMPLE), 64); }
 
-            if let Some(c) = hash_to_cycle.get(&hash) {
+    #[test]
+    fn example_hash() {
 
-                return (cycle - c, cycle);
-            }
-            else {
+        let mut platform = Platform::parse(EXAMPLE);
 
-                hash_to_cycle.insert(hash, cycle);
-            }
+        for _ in 0 .. 3 { cycle_platform(&mut platform); }
 
-            cycle_platform(platform);
+        let rehashed = Zobrist::new(&platform.grid, 3, to_variant);
 
-            cycle += 1;
-        }
+        assert_eq!(platform.hash.hash(), rehashed.hash());
     }
 
-    fn get_result(input: &str) -> usize {
-
-        let mut platform = Platform::parse(input);
-
-        let (period, cycles) = cycle_period(&mut platform);
-
-        for _ in 0 .. (1_000_000_000 - cycles) % period {
+    #[test]
+    fn example_cycles() {
+
+        let mut platform = Platform::parse(EXAMPLE);
+
+        let expected = [".....#....\n\
+                         ....#...O#\n\
+                         ...OO##...\n\
+                         .OO#......\n\
+                         .....OOO#.\n\
+                         .O#...O#.#\n\
+                         ....O#....\n\
+                         ......OOOO\n\
+                         #...O###..\n\
+                         #..OO#....",
+
+                        ".....#....\n\
+                         ....#...O#\n\
+                         .....##...\n\
+                         ..O#......\n\
+                         .....OOO#.\n\
+                         .O#...O#.#\n\
+                         ....O#...O\n\
+                         .......OOO\n\
+                         #..OO###..\n\
+                         #.OOO#...O",
+
+                        ".....#....\n\
+                         ....#...O#\n\
+                         .....##...\n\
+                         ..O#......\n\
+                         .....OOO#.\n\
+                         .O#...O#.#\n\
+                         ....O#...O\n\
+                         .......OOO\n\
+                         #...O###.O\n\
+                         #.OOO#...O"];
+
+        for text in expected {
 
             cycle_platform(&mut platform);
-        }
 
-        platform.total_north_load()
+            assert_eq!(platform.grid.to_string(), text);
+        }
     }
-
-    #[test]
-    fn example() { assert_eq!(get_result(EXAMPLE), 64); }
     
     #[test]
     fn real() { assert_eq!(get_result(INPUT), 100531); }
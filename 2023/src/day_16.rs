@@ -13,6 +13,30 @@ use Mirror::*;
 #[derive(Clone)]
 struct Cell { mirror: Option<Mirror>, beams: [bool; 4] }
 
+impl GridCell for Cell {
+
+    fn from_char(char: char) -> Option<Self> {
+
+        let mirror = match char { '.'  => None,
+                                  '|'  => Some(Vertical),
+                                  '-'  => Some(Horizontal),
+                                  '\\' => Some(LeftLean),
+                                  '/'  => Some(RightLean),
+                                  _    => { return None; } };
+
+        Some(Cell { beams: Default::default(), mirror })
+    }
+
+    fn to_char(&self) -> char {
+
+        match self.mirror { None             => '.',
+                            Some(Vertical)   => '|',
+                            Some(Horizontal) => '-',
+                            Some(LeftLean)   => '\\',
+                            Some(RightLean)  => '/' }
+    }
+}
+
 #[derive(Clone)]
 struct Contraption { grid: Grid<Cell> }
 
@@ -20,19 +44,7 @@ impl Contraption {
 
     fn parse(input: &str) -> Self {
 
-        let parse_char = |c| {
-
-            let mirror = match c { '.'  => None,
-                                   '|'  => Some(Vertical),
-                                   '-'  => Some(Horizontal),
-                                   '\\' => Some(LeftLean),
-                                   '/'  => Some(RightLean),
-                                   _    => { return None; } };
-
-            Some(Cell { beams: Default::default(), mirror })
-        };
-
-        Contraption { grid: Grid::parse(input, parse_char).unwrap() }
+        Contraption { grid: Grid::parse_cells(input).unwrap() }
     }
 
     fn add_beams(&mut self, mut coord: Option<Coord>, mut direction: Direction) {
@@ -91,6 +103,12 @@ mod part_1 {
 
     #[test]
     fn example() { assert_eq!(get_result(EXAMPLE), 46); }
+
+    #[test]
+    fn example_round_trip() {
+
+        assert_eq!(Contraption::parse(EXAMPLE).grid.to_string(), EXAMPLE);
+    }
     
     #[test]
     fn real() { assert_eq!(get_result(INPUT), 7860); }
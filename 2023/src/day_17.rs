@@ -1,8 +1,4 @@
 
-// TODO: This is very slow. 10 mins to solve part 1
-//       There must be a better way!
-//       Do I have too many states per cell?
-
 const INPUT: &str = include_str!("../input/day_17.txt");
 
 const EXAMPLE: &str = "2413432311323\n\
@@ -19,92 +15,98 @@ const EXAMPLE: &str = "2413432311323\n\
                        2546548887735\n\
                        4322674655533";
 
-use super::grid::{ *, Direction:: * };
+use super::{ grid::{ *, Direction::* }, search::* };
 
-struct Block { loss: usize, min_total_losses: [usize; 4 * 3] }
+struct City { grid: Grid<usize> }
 
-struct City { grid: Grid<Block> }
+type Crucible = (Coord, Direction, usize); // Run of blocks in a straight line
 
 impl City {
 
     fn parse(text: &str) -> Self {
 
-        let parse_char = |c: char|
-            Some(Block { loss: c.to_string().parse().unwrap(),
-                         min_total_losses: [usize::MAX; 4 * 3] });
+        let parse_char = |c: char| c.to_digit(10).map(|d| d as usize);
 
         City { grid: Grid::parse(text, parse_char).unwrap() }
     }
 
-    fn min_loss(&mut self) -> usize {
-
-        let start_coord = Coord::new(0, 0);
-
-        let end_coord = Coord::new(self.grid.width() - 1,
-                                   self.grid.height() - 1);
-
-        let mut stack = vec![(start_coord, 0usize, Right),
-                             (start_coord, 0usize, Down)];
+    fn min_loss(&self, min_run: usize, max_run: usize) -> usize {
 
-        let to_index = |i, d: Direction| d.to_index() * 3 + i;
+        let (width, height) = (self.grid.width(), self.grid.height());
 
-        while let Some((mut coord, mut total_loss, direction)) = stack.pop() {
+        let end_coord = Coord::new(width - 1, height - 1);
 
-            for forward_index in 0 .. 3 {
+        let index = |&(coord, direction, run): &Crucible|
+            ((coord.y * width + coord.x) * 4 + direction.to_index())
+            * (max_run + 1) + run;
 
-                coord = if let Some(c) = coord + direction { c }
-                        else { break; };
+        let successors = |&(coord, direction, run): &Crucible| {
 
-                let Some(block) = self.grid.get_at_mut(Some(coord))
-                                  else { break; };
+            let turns = (run >= min_run).then_some([Turn::Left, Turn::Right]);
 
-                total_loss += block.loss;
+            (run < max_run).then_some((direction, run + 1))
+                .into_iter()
+                .chain(turns.into_iter()
+                            .flatten()
+                            .map(move |t| (direction.turned(t), 1)))
+                .filter_map(move |(direction, run)| {
+                    let coord = (coord + direction)?;
+                    let &loss = self.grid.get_at(Some(coord))?;
+                    Some(((coord, direction, run), loss))
+                })
+        };
 
-                let index = to_index(forward_index, direction);
+        let origin = Coord::new(0, 0);
 
-                if block.min_total_losses[index] <= total_loss { break; }
+        astar_with(Indexed::new(width * height * 4 * (max_run + 1), index),
+                   Buckets::default(),
+                   [(origin, Right, 0), (origin, Down, 0)],
+                   successors,
+                   manhattan(end_coord, |&(coord, _, _)| coord),
+                   |&(coord, _, run)| coord == end_coord && run >= min_run)
+            .goal_cost()
+            .unwrap()
+    }
+}
 
-                block.min_total_losses[index] = total_loss;
+mod part_1 {
 
-                for i in forward_index + 1 .. 3 {
+    use super::*;    
 
-                    block.min_total_losses[to_index(i, direction)] = total_loss;
-                }
+    fn get_result(input: &str) -> usize {
 
-                if coord == end_coord { continue; }
+        City::parse(input).min_loss(1, 3)
+    }
 
-                if coord.x != self.grid.width() - 1 && coord.y != 0 {
+    #[test]
+    fn example() { assert_eq!(get_result(EXAMPLE), 102); }
+    
+    #[test]
+    fn real() { assert_eq!(get_result(INPUT), 851); }
+}
 
-                    stack.push((coord, total_loss, direction.turned(Turn::Left)));
-                }
+mod part_2 {
 
-                if coord.y != self.grid.height() - 1 && coord.x != 0 {
+    use super::*;
 
-                    stack.push((coord, total_loss, direction.turned(Turn::Right)));
-                }
-            }
-        }
+    fn get_result(input: &str) -> usize {
 
-        self.grid.get_at(Some(end_coord))
-                 .unwrap()
-                 .min_total_losses
-                 .iter()
-                 .copied()
-                 .min()
-                 .unwrap()
+        City::parse(input).min_loss(4, 10)
     }
-}
 
-mod part_1 {
+    #[test]
+    fn example() { assert_eq!(get_result(EXAMPLE), 94); }
 
-    use super::*;    
+    #[test]
+    fn example_unfortunate() {
 
-    fn get_result(input: &str) -> usize { City::parse(input).min_loss() }
+        assert_eq!(get_result("111111111111\n\
+                               999999999991\n\
+                               999999999991\n\
+                               999999999991\n\
+                               999999999991"), 71);
+    }
 
     #[test]
-    fn example() { assert_eq!(get_result(EXAMPLE), 102); }
-    
-    #[ignore = "slow"]
-    #[test]
-    fn real() { assert_eq!(get_result(INPUT), 851); }
-}
\ No newline at end of file
+    fn real() { assert_eq!(get_result(INPUT), 982); }
+}
@@ -16,101 +16,24 @@ const EXAMPLE: &str = "R 6 (#70c710)\n\
                        L 2 (#015232)\n\
                        U 2 (#7a21e3)";
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
-struct Coord { x: isize, y: isize }
+use super::{ geometry::*, grid::Direction };
 
-use std::collections::BTreeMap;
+fn lagoon_size(moves: &[(Direction, usize)]) -> u64 {
 
-use crate::grid::{ Direction, Direction::* };
-
-fn to_map(directions: &[(Direction, usize)]) -> BTreeMap<Coord, [Direction; 2]> {
-
-    let mut map = BTreeMap::new();
-
-    let mut coord = Coord { x: 0, y: 0 };
-
-    let mut previous_direction = Direction::Up;
-
-    for (direction, distance) in directions.iter().copied() {
-
-        for _ in 0 .. distance {
-
-            map.insert(coord, coersed([previous_direction, direction]));
-
-            previous_direction = direction;
-
-            coord = match direction {
-                Up    => Coord { y: coord.y - 1, ..coord },
-                Down  => Coord { y: coord.y + 1, ..coord },
-                Left  => Coord { x: coord.x - 1, ..coord },
-                Right => Coord { x: coord.x + 1, ..coord }
-            };
-        }
-    }
-
-    *map.get_mut(&coord).unwrap() = coersed([directions.last().unwrap().0,
-                                             directions.first().unwrap().0]);
-    map
-}
-
-fn coersed(directions: [Direction; 2]) -> [Direction; 2] {
-
-    match directions { [Up,   Left] => [Right, Down],
-                       [Down, Down] => [Up,    Up],
-                       [Down, Left] => [Right, Up],
-                       [Left, Up]   => [Down,  Right],
-                       [Left, Down] => [Up,    Right],
-                       [Left, Left] => [Right, Right],
-                       _            => directions }
-}
-
-fn score(map: &BTreeMap<Coord, [Direction; 2]>) -> usize {
-
-    let mut score = 0;
-
-    let (min_x, max_x, min_y, max_y) = (map.keys().map(|c| c.x).min().unwrap(),
-                                        map.keys().map(|c| c.x).max().unwrap(),
-                                        map.keys().map(|c| c.y).min().unwrap(),
-                                        map.keys().map(|c| c.y).max().unwrap());
-    for y in min_y ..= max_y {
-
-        let mut inside = false;
-
-        let mut toggle_if = None;
-
-        for x in min_x ..= max_x {
-
-            if let Some(directions) = map.get(&Coord { x, y }) {
-
-                match directions {
-                    [Up, Up] => inside = !inside,
-                    [Right, d] if Some(*d) == toggle_if => { inside = !inside; },
-                    [d, Right] if *d != Right => toggle_if = Some(*d),
-                    _ => {}
-                }
-
-                score += 1;                
-            }
-            else if inside { score += 1; }
-        }
-    }
-
-    score
+    Polygon::from_moves(moves.iter().copied()).lattice_points()
 }
 
 mod part_1 {
 
     use super::*;
 
-    fn get_result(input: &str) -> usize {
+    fn get_result(input: &str) -> u64 {
 
-        let parse_dir = |text| {
-    
-            match text { "U" => Up,
-                         "D" => Down,
-                         "L" => Left,
-                         "R" => Right,
-                         _   => panic!() }
+        let parse_dir = |text: &str| {
+
+            let char = text.chars().next().unwrap();
+
+            Direction::parse(char, Direction::LETTERS).unwrap()
         };
 
         let map =
@@ -119,7 +42,7 @@ mod part_1 {
                  .map(|v| (parse_dir(v[0]), v[1].parse::<usize>().unwrap()))
                  .collect::<Vec<_>>();
         
-        score(&to_map(&map))
+        lagoon_size(&map)
     }
 
     #[test]
@@ -133,30 +56,28 @@ mod part_2 {
 
     use super::*;
 
-    fn get_result(input: &str) -> usize {
+    fn get_result(input: &str) -> u64 {
 
-        let parse_dir = |text| {
-    
-            match text { "0" => Right,
-                         "1" => Down,
-                         "2" => Left,
-                         "3" => Up,
-                         _   => panic!() }
+        let parse_dir = |text: &str| {
+
+            let char = text.chars().nth(7).unwrap();
+
+            Direction::parse(char, Direction::DIGITS).unwrap()
         };
 
         let map =
             input.split('\n')
-                 .map(|l| l.split(' ').last().unwrap())
-                 .map(|t| (parse_dir(&t[7 ..= 7]),
+                 .map(|l| l.split(' ').next_back().unwrap())
+                 .map(|t| (parse_dir(t),
                            usize::from_str_radix(&t[2 .. 7], 16).unwrap()))
                  .collect::<Vec<_>>();
         
-        score(&to_map(&map))
+        lagoon_size(&map)
     }
 
     #[test]
     fn example() { assert_eq!(get_result(EXAMPLE), 952408144115); }
     
     #[test]
-    fn real() { assert_eq!(get_result(INPUT), 0); }
+    fn real() { assert_eq!(get_result(INPUT), 92556825427032); }
 }
\ No newline at end of file
@@ -12,12 +12,12 @@ use std::collections::BTreeMap;
 
 type BallCounts<'a> = BTreeMap<&'a str, usize>;
 
-fn to_draws(text: &str) -> Vec<BallCounts> {
+fn to_draws(text: &str) -> Vec<BallCounts<'_>> {
 
     text.split(';').map(to_draw).collect()
 }
 
-fn to_draw(text: &str) -> BallCounts {
+fn to_draw(text: &str) -> BallCounts<'_> {
 
     text.split(',')
         .map(|p| p.trim().split(' ').collect::<Vec<_>>())
@@ -5,31 +5,59 @@ const EXAMPLE: &str = "Time:      7  15   30\n\
 const INPUT: &str = "Time:        60     94     78     82\n\
                      Distance:   475   2138   1015   1650";
 
-fn get_record_beating_count(race_time: usize, record: usize) -> usize {
+use super::number::*;
 
-    let root = ((race_time * race_time - 4 * record) as f64).sqrt();
-    
-    let mut high = ((race_time as f64 + root) / 2f64).floor() as usize;
-    let mut low =  ((race_time as f64 - root) / 2f64).ceil()  as usize;
+fn get_record_beating_count(race_time: u128, record: u128) -> u128 {
+
+    count_strictly_between_roots(race_time, record)
+}
+
+#[test]
+fn brute_force() {
+
+    for race_time in 0 .. 60 {
+
+        for record in 0 .. 1000 {
+
+            let count = (0 ..= race_time)
+                        .filter(|p| (race_time - p) * p > record)
+                        .count();
+
+            assert_eq!(get_record_beating_count(race_time, record),
+                       count as u128);
+        }
+    }
+}
+
+#[test]
+fn huge() { // Squaring the time overflows u64
+
+    let (half, spread) = (1_000_000_000_000_000u128, 12_345);
+
+    let record = half * half - spread * spread;
+
+    assert_eq!(get_record_beating_count(2 * half, record), 2 * spread - 1);
+
+    assert_eq!(get_record_beating_count(2 * half, record - 1), 2 * spread + 1);
 
-    let is_record = |p: usize| (race_time - p) * p > record;
+    assert_eq!(get_record_beating_count(1 << 64, 0), (1 << 64) - 1);
 
-    if !is_record(high) { high -= 1; }
-    if !is_record(low)  { low  += 1; }
+    assert_eq!(get_record_beating_count(1 << 70, 1 << 100),
+               1_180_591_620_715_263_819_775);
 
-    high - low + 1
+    assert_eq!(get_record_beating_count(u128::MAX, u128::MAX), u128::MAX - 3);
 }
 
 mod part_1 {
 
     use super::*;
 
-    fn get_result(input: &str) -> usize {
+    fn get_result(input: &str) -> u128 {
 
         let numbers = input.split('\n')
                            .map(|l| l.split_whitespace().skip(1))
                            .map(|t| t.map(|s| s.parse().unwrap()))
-                           .map(|n| n.collect::<Vec<usize>>())
+                           .map(|n| n.collect::<Vec<u128>>())
                            .collect::<Vec<_>>();
         numbers[0].iter()
                   .zip(&numbers[1])
@@ -48,13 +76,13 @@ mod part_2 {
 
     use super::*;
 
-    fn get_result(input: &str) -> usize {
+    fn get_result(input: &str) -> u128 {
 
         let numbers = input.replace(' ', "")
                            .split('\n')
                            .map(|l| l.split(':').nth(1))
                            .map(|t| t.unwrap().parse().unwrap())
-                           .collect::<Vec<usize>>();
+                           .collect::<Vec<u128>>();
         
         get_record_beating_count(numbers[0], numbers[1])
     }
@@ -3,22 +3,22 @@ const EXAMPLE_A: &str = include_str!("../examples/day_8_a.txt");
 
 const EXAMPLE_B: &str = include_str!("../examples/day_8_b.txt");
 
+const EXAMPLE_C: &str = include_str!("../examples/day_8_c.txt");
+
 const INPUT: &str = include_str!("../input/day_8.txt");
 
-use std::collections::BTreeMap;
+use super::{ cycle::*, graph::*, number::* };
 
-type Nodes<'a> = BTreeMap<&'a str, (&'a str, &'a str)>;
+type Network<'a> = Graph<&'a str>; // Each node's successors are [left, right]
 
-fn parse<'a>(input: &'a str) ->
-    (Box<dyn Iterator<Item = bool> + 'a>, Nodes<'a>) {
+fn parse(input: &str) -> (Vec<bool>, Network<'_>) { // Lefts are true
 
     let lines = input.split('\n').collect::<Vec<_>>();
 
-    let nodes =
-        lines[2 ..].iter().map(|l| (&l[.. 3], (&l[7 .. 10], &l[12 .. 15])));
+    let edges = lines[2 ..].iter().flat_map(|l| [(&l[.. 3], &l[7 .. 10]),
+                                                 (&l[.. 3], &l[12 .. 15])]);
 
-    (Box::new(lines[0].chars().map(|c| c == 'L').cycle()),
-     BTreeMap::from_iter(nodes))
+    (lines[0].chars().map(|c| c == 'L').collect(), Graph::from_edges(edges))
 }
 
 mod part_1 {
@@ -27,19 +27,20 @@ mod part_1 {
 
     fn get_result(input: &str) -> usize {
 
-        let (directions, nodes) = parse(input);
+        let (directions, network) = parse(input);
 
-        let mut node = "AAA";
+        let (mut node, end) = (network.get_id(&"AAA").unwrap(),
+                               network.get_id(&"ZZZ").unwrap());
 
         let mut steps = 0;
 
-        for direction in directions {
+        for &direction in directions.iter().cycle() {
 
             steps += 1;
 
-            node = if direction { nodes[node].0 } else { nodes[node].1 };
+            node = network.successors(node)[if direction { 0 } else { 1 }];
 
-            if node == "ZZZ" { break; }
+            if node == end { break; }
         };
 
         steps
@@ -53,4 +54,77 @@ mod part_1 {
     
     #[test]
     fn real() { assert_eq!(get_result(INPUT), 12169); }
-}
\ No newline at end of file
+}
+
+mod part_2 {
+
+    use super::*;
+
+    struct Ghost { prefix: usize, period: usize, z_steps: Vec<usize> }
+
+    fn haunt(network: &Network, directions: &[bool], start: usize) -> Ghost {
+
+        let step = |&(node, i): &(usize, usize)|
+            (network.successors(node)[if directions[i] { 0 } else { 1 }],
+             (i + 1) % directions.len());
+
+        let cycle = find_cycle((start, 0), step, |&s| s);
+
+        let z_steps = (0 .. cycle.prefix + cycle.period)
+            .filter(|&t| network.name(cycle.state_at(t).0).ends_with('Z'))
+            .collect();
+
+        Ghost { prefix: cycle.prefix, period: cycle.period, z_steps }
+    }
+
+    fn get_result(input: &str) -> usize {
+
+        let (directions, network) = parse(input);
+
+        let ghosts = (0 .. network.len())
+            .filter(|&n| network.name(n).ends_with('A'))
+            .map(|n| haunt(&network, &directions, n))
+            .collect::<Vec<_>>();
+
+        let settled = ghosts.iter().map(|g| g.prefix).max().unwrap();
+
+        // Before every ghost is in its loop, it's quickest to check each step
+        let at_z = |g: &Ghost, t: usize| g.z_steps.contains(&match t {
+            t if t < g.prefix => t,
+            t => g.prefix + (t - g.prefix) % g.period
+        });
+
+        let all_at_z = |t| ghosts.iter().all(|g| at_z(g, t));
+
+        if let Some(t) = (0 .. settled).find(|&t| all_at_z(t)) { return t; }
+
+        // After that each ghost is at a Z on one of its residues
+        let combine = |solutions: Vec<(i64, i64)>, ghost: &Ghost|
+            solutions.iter()
+                     .flat_map(|&s| ghost.z_steps
+                                         .iter()
+                                         .filter(|&&z| z >= ghost.prefix)
+                                         .map(move |&z| (s, z)))
+                     .filter_map(|(s, z)|
+                         crt([s, (z as i64, ghost.period as i64)]))
+                     .collect();
+
+        let first_after_settled = |(residue, modulus): (i64, i64)| {
+            let (r, m) = (residue as usize, modulus as usize);
+            r + settled.saturating_sub(r).div_ceil(m) * m
+        };
+
+        ghosts.iter()
+              .fold(vec![(0, 1)], combine)
+              .into_iter()
+              .map(first_after_settled)
+              .min()
+              .unwrap()
+    }
+
+    #[test]
+    fn example() { assert_eq!(get_result(EXAMPLE_C), 6); }
+
+    #[test]
+    fn real() { assert_eq!(get_result(INPUT), 12030780859469); }
+}
@@ -5,37 +5,29 @@ const EXAMPLE: &str = "0 3 6 9 12 15\n\
 
 const INPUT: &str = include_str!("../input/day_9.txt");
 
-fn diffs(line: &str) -> Vec<Vec<isize>> {
+use super::sequence::*;
 
-    let mut diffs = Vec::from
-        ([line.split_whitespace()
-              .map(|t| t.parse::<isize>().unwrap())
-              .collect::<Vec<_>>()]);
-    loop {
+fn parse(line: &str) -> (Polynomial, i64) { // And the sample count
 
-        let last = diffs.last().unwrap();
+    let samples = line.split_whitespace()
+                      .map(|t| t.parse::<i64>().unwrap())
+                      .collect::<Vec<_>>();
 
-        let next = last.iter()
-                       .zip(last.iter().skip(1))
-                       .map(|(a, b)| b - a)
-                       .collect::<Vec<_>>();
-
-        if next.iter().all(|&v| v == 0) { return diffs; }
-
-        diffs.push(next);
-    }
+    (Polynomial::fit(&samples).unwrap(), samples.len() as i64)
 }
 
 mod part_1 {
 
     use super::*;
 
-    fn next_val(line: &str) -> isize {
+    fn next_val(line: &str) -> i128 {
+
+        let (polynomial, len) = parse(line);
 
-        diffs(line).iter().rev().fold(0, |a, d| a + d.last().unwrap())
+        polynomial.at(len)
     }
 
-    fn get_result(input: &str) -> isize {
+    fn get_result(input: &str) -> i128 {
 
         input.split('\n').map(next_val).sum()
     }
@@ -51,12 +43,12 @@ mod part_2 {
 
     use super::*;
 
-    fn next_val(line: &str) -> isize {
+    fn next_val(line: &str) -> i128 {
 
-        diffs(line).iter().rev().fold(0, |a, d| d.first().unwrap() - a)
+        parse(line).0.at(-1)
     }
 
-    fn get_result(input: &str) -> isize {
+    fn get_result(input: &str) -> i128 {
 
         input.split('\n').map(next_val).sum()
     }
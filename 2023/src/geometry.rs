@@ -0,0 +1,117 @@
+
+use super::{ grid::{ Coord, Direction }, number::gcd };
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon { vertices: Vec<(i64, i64)> } // Lattice points, in order
+
+impl Polygon {
+
+    pub fn from_vertices(vertices: impl IntoIterator<Item = (i64, i64)>)
+        -> Self { // The loop may or may not repeat its first vertex
+
+        let mut vertices = vertices.into_iter().collect::<Vec<_>>();
+
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+
+            vertices.pop();
+        }
+
+        Self { vertices }
+    }
+
+    pub fn from_coords(coords: impl IntoIterator<Item = Coord>) -> Self {
+
+        Self::from_vertices(coords.into_iter()
+                                  .map(|c| (c.x as i64, c.y as i64)))
+    }
+
+    pub fn from_moves(moves: impl IntoIterator<Item = (Direction, usize)>)
+        -> Self { // Starting from the origin
+
+        let mut vertex = (0, 0);
+
+        let vertices = moves.into_iter().map(|(direction, distance)| {
+
+            let (x, y) = direction.to_offset();
+
+            vertex.0 += x as i64 * distance as i64;
+            vertex.1 += y as i64 * distance as i64;
+
+            vertex
+        });
+
+        Self::from_vertices(vertices.collect::<Vec<_>>())
+    }
+
+    fn edges(&self) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    pub fn twice_area(&self) -> u64 { // Shoelace, so always whole
+
+        self.edges()
+            .map(|((ax, ay), (bx, by))| ax * by - bx * ay)
+            .sum::<i64>()
+            .unsigned_abs()
+    }
+
+    pub fn boundary_points(&self) -> u64 {
+
+        self.edges()
+            .map(|((ax, ay), (bx, by))| gcd(ax.abs_diff(bx), ay.abs_diff(by)))
+            .sum()
+    }
+
+    // By Pick's theorem, which only holds for a simple loop. One that
+    // retraces itself has too many boundary points for that, and is
+    // clamped to no interior rather than underflowing
+    pub fn interior_points(&self) -> u64 {
+
+        (self.twice_area() + 2).saturating_sub(self.boundary_points()) / 2
+    }
+
+    // Boundary included. A retraced boundary is counted each time round,
+    // so this is only exact for a simple loop
+    pub fn lattice_points(&self) -> u64 {
+
+        self.interior_points() + self.boundary_points()
+    }
+}
+
+#[test]
+fn test_polygon() {
+
+    let square = Polygon::from_vertices([(0, 0), (4, 0), (4, 4), (0, 4)]);
+
+    assert_eq!(square.twice_area(), 32);
+
+    assert_eq!(square.boundary_points(), 16);
+
+    assert_eq!(square.interior_points(), 9);
+
+    assert_eq!(square.lattice_points(), 25);
+
+    let triangle = Polygon::from_vertices([(0, 0), (3, 1), (1, 3), (0, 0)]);
+
+    assert_eq!(triangle.twice_area(), 8);
+
+    assert_eq!(triangle.boundary_points(), 4);
+
+    assert_eq!(triangle.interior_points(), 3);
+
+    use Direction::*;
+
+    let ell = Polygon::from_moves([(Right, 2), (Down, 1), (Left, 1),
+                                   (Down, 1), (Left, 1), (Up, 2)]);
+
+    assert_eq!((ell.twice_area(), ell.lattice_points()), (6, 8));
+
+    let there_and_back = Polygon::from_moves([(Right, 3), (Left, 3)]);
+
+    assert_eq!((there_and_back.twice_area(),
+                there_and_back.interior_points()), (0, 0));
+}
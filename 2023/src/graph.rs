@@ -0,0 +1,295 @@
+
+use std::{ collections::{ HashMap, VecDeque }, hash::Hash };
+
+pub struct Graph<N> { // Nodes are interned as ids, in order of appearance
+
+    names: Vec<N>,
+    ids: HashMap<N, usize>,
+    edges: Vec<Vec<usize>>
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+
+    pub fn new() -> Self {
+
+        Self { names: Vec::new(), ids: HashMap::new(), edges: Vec::new() }
+    }
+
+    pub fn from_edges(edges: impl IntoIterator<Item = (N, N)>) -> Self {
+
+        let mut graph = Self::new();
+
+        for (from, to) in edges { graph.add_edge(from, to); }
+
+        graph
+    }
+
+    pub fn id(&mut self, name: N) -> usize { // Interning it if new
+
+        if let Some(&id) = self.ids.get(&name) { return id; }
+
+        self.names.push(name.clone());
+        self.edges.push(Vec::new());
+
+        self.ids.insert(name, self.names.len() - 1);
+
+        self.names.len() - 1
+    }
+
+    pub fn get_id(&self, name: &N) -> Option<usize> {
+
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &N { &self.names[id] }
+
+    pub fn len(&self) -> usize { self.names.len() }
+
+    pub fn add_edge(&mut self, from: N, to: N) { // Kept in insertion order
+
+        let (from, to) = (self.id(from), self.id(to));
+
+        self.edges[from].push(to);
+    }
+
+    pub fn successors(&self, id: usize) -> &[usize] { &self.edges[id] }
+
+    pub fn bfs(&self, start: usize) -> Vec<Option<usize>> { // Edge counts
+
+        let mut distances = vec![None; self.len()];
+
+        distances[start] = Some(0);
+
+        let mut todo = VecDeque::from([start]);
+
+        while let Some(id) = todo.pop_front() {
+
+            for &next in &self.edges[id] {
+
+                if distances[next].is_some() { continue; }
+
+                distances[next] = distances[id].map(|d| d + 1);
+
+                todo.push_back(next);
+            }
+        }
+
+        distances
+    }
+
+    pub fn dfs(&self, start: usize) -> Vec<usize> { // In preorder
+
+        let mut visited = vec![false; self.len()];
+
+        let mut order = Vec::new();
+
+        let mut todo = vec![start];
+
+        while let Some(id) = todo.pop() {
+
+            if visited[id] { continue; }
+
+            visited[id] = true;
+
+            order.push(id);
+
+            todo.extend(self.edges[id].iter().rev());
+        }
+
+        order
+    }
+
+    // Tarjan's algorithm, with an explicit call stack. Components come out
+    // in reverse topological order
+    pub fn sccs(&self) -> Vec<Vec<usize>> {
+
+        let mut index = vec![usize::MAX; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+
+        let (mut stack, mut sccs, mut next_index) = (Vec::new(), Vec::new(), 0);
+
+        for root in 0 .. self.len() {
+
+            if index[root] != usize::MAX { continue; }
+
+            let mut calls = vec![(root, 0)];
+
+            while let Some(&(id, edge)) = calls.last() {
+
+                if edge == 0 {
+
+                    (index[id], low[id]) = (next_index, next_index);
+
+                    next_index += 1;
+
+                    stack.push(id);
+                    on_stack[id] = true;
+                }
+
+                if let Some(&next) = self.edges[id].get(edge) {
+
+                    calls.last_mut().unwrap().1 += 1;
+
+                    if index[next] == usize::MAX { calls.push((next, 0)); }
+                    else if on_stack[next] {
+
+                        low[id] = low[id].min(index[next]);
+                    }
+
+                    continue;
+                }
+
+                calls.pop();
+
+                if let Some(&(parent, _)) = calls.last() {
+
+                    low[parent] = low[parent].min(low[id]);
+                }
+
+                if low[id] == index[id] {
+
+                    let mut scc = Vec::new();
+
+                    while let Some(member) = stack.pop() {
+
+                        on_stack[member] = false;
+
+                        scc.push(member);
+
+                        if member == id { break; }
+                    }
+
+                    sccs.push(scc);
+                }
+            }
+        }
+
+        sccs
+    }
+
+    pub fn topological_sort(&self) -> Result<Vec<usize>, Vec<usize>> {
+
+        self.sort_subset(&(0 .. self.len()).collect::<Vec<_>>())
+    }
+
+    // Kahn's algorithm over the subgraph induced by ids, breaking ties by
+    // their order in ids. On failure gives a cycle, each member leading to
+    // the next and the last back to the first
+    pub fn sort_subset(&self, ids: &[usize]) -> Result<Vec<usize>, Vec<usize>> {
+
+        let mut in_subset = vec![false; self.len()];
+
+        for &id in ids { in_subset[id] = true; }
+
+        let mut in_degrees = vec![0; self.len()];
+
+        for &id in ids {
+
+            for &next in self.edges[id].iter().filter(|&&n| in_subset[n]) {
+
+                in_degrees[next] += 1;
+            }
+        }
+
+        let mut todo = ids.iter()
+                          .copied()
+                          .filter(|&id| in_degrees[id] == 0)
+                          .collect::<VecDeque<_>>();
+
+        let mut sorted = Vec::new();
+
+        while let Some(id) = todo.pop_front() {
+
+            sorted.push(id);
+
+            for &next in self.edges[id].iter().filter(|&&n| in_subset[n]) {
+
+                in_degrees[next] -= 1;
+
+                if in_degrees[next] == 0 { todo.push_back(next); }
+            }
+        }
+
+        if sorted.len() == ids.len() { return Ok(sorted); }
+
+        // Everything left has a predecessor left, so walking back repeats
+        let left = |id: usize| in_subset[id] && in_degrees[id] > 0;
+
+        let predecessor = |id: usize|
+            ids.iter()
+               .copied()
+               .find(|&p| left(p) && self.edges[p].contains(&id))
+               .unwrap();
+
+        let mut walk = vec![ids.iter().copied().find(|&id| left(id)).unwrap()];
+
+        loop {
+
+            let previous = predecessor(*walk.last().unwrap());
+
+            if let Some(start) = walk.iter().position(|&id| id == previous) {
+
+                let mut cycle = walk.split_off(start);
+
+                cycle.reverse();
+
+                return Err(cycle);
+            }
+
+            walk.push(previous);
+        }
+    }
+
+    pub fn respects_order(&self, ids: &[usize]) -> bool { // No edge goes back
+
+        ids.iter().enumerate().all(|(i, &id)|
+            self.edges[id].iter().all(|next| !ids[.. i].contains(next)))
+    }
+}
+
+impl<N: Clone + Eq + Hash> Default for Graph<N> {
+
+    fn default() -> Self { Self::new() }
+}
+
+#[test]
+fn test_graph() {
+
+    let graph = Graph::from_edges([('a', 'b'), ('b', 'c'), ('c', 'a'),
+                                   ('c', 'd'), ('d', 'e'), ('e', 'd'),
+                                   ('f', 'e')]);
+
+    let id = |name| graph.get_id(&name).unwrap();
+
+    let names = |ids: &[usize]| ids.iter()
+                                   .map(|&id| *graph.name(id))
+                                   .collect::<String>();
+
+    assert_eq!(graph.bfs(id('a'))[id('e')], Some(4));
+
+    assert_eq!(graph.bfs(id('d'))[id('a')], None);
+
+    assert_eq!(names(&graph.dfs(id('a'))), "abcde");
+
+    let sccs = graph.sccs()
+                    .iter()
+                    .map(|scc| { let mut n = names(scc).into_bytes();
+                                 n.sort();
+                                 String::from_utf8(n).unwrap() })
+                    .collect::<Vec<_>>();
+
+    assert_eq!(sccs, ["de", "abc", "f"]);
+
+    let cycle = graph.topological_sort().unwrap_err();
+
+    assert!(["abc", "bca", "cab", "de", "ed"].contains(&&*names(&cycle)));
+
+    let subset = [id('f'), id('d'), id('c'), id('b')];
+
+    assert_eq!(names(&graph.sort_subset(&subset).unwrap()), "fbcd");
+
+    assert!(graph.respects_order(&[id('b'), id('c'), id('d')]));
+
+    assert!(!graph.respects_order(&[id('c'), id('b')]));
+}
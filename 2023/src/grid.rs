@@ -1,13 +1,33 @@
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Direction { Up, Down, Left, Right }
 
 use Direction::*;
 
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Turn { Left, Right }
 
 impl Direction {
 
+    pub const ALL: [Direction; 4] = [Up, Down, Left, Right];
+
+    pub const ARROWS:  [char; 4] = ['^', 'v', '<', '>']; // In ALL order
+    pub const LETTERS: [char; 4] = ['U', 'D', 'L', 'R'];
+    pub const COMPASS: [char; 4] = ['N', 'S', 'W', 'E'];
+    pub const DIGITS:  [char; 4] = ['3', '1', '2', '0'];
+
+    pub fn parse(char: char, char_set: [char; 4]) -> Option<Direction> {
+
+        char_set.iter().position(|&c| c == char).map(Self::from_index)
+    }
+
+    pub fn to_char(self, char_set: [char; 4]) -> char {
+
+        char_set[self.to_index()]
+    }
+
     pub fn turned(self, turn: Turn) -> Direction {
 
         let right_turned = match self { Up    => Right,
@@ -26,6 +46,16 @@ impl Direction {
                      Left   => 2,
                      Right  => 3 }
     }
+
+    pub fn from_index(index: usize) -> Direction { Self::ALL[index] }
+
+    pub fn to_offset(self) -> (isize, isize) {
+
+        match self { Up    => ( 0, -1),
+                     Down  => ( 0,  1),
+                     Left  => (-1,  0),
+                     Right => ( 1,  0) }
+    }
 }
 
 impl std::fmt::Display for Direction {
@@ -49,10 +79,83 @@ impl std::ops::Not for Direction {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 { North, NorthEast, East, SouthEast,
+                      South, SouthWest, West, NorthWest }
+
+impl Direction8 {
+
+    pub const ALL: [Direction8; 8] = { use Direction8::*;
+                                       [North, NorthEast, East, SouthEast,
+                                        South, SouthWest, West, NorthWest] };
+
+    pub fn turned(self, turn: Turn) -> Direction8 { // By 45 degrees
+
+        Self::ALL[match turn { Turn::Right => self.to_index() + 1,
+                               Turn::Left  => self.to_index() + 7 } % 8]
+    }
+
+    pub fn to_index(self) -> usize { self as usize } // Clockwise from North
+
+    pub fn from_index(index: usize) -> Direction8 { Self::ALL[index] }
+
+    pub fn to_offset(self) -> (isize, isize) {
+
+        use Direction8::*;
+
+        match self { North     => ( 0, -1),
+                     NorthEast => ( 1, -1),
+                     East      => ( 1,  0),
+                     SouthEast => ( 1,  1),
+                     South     => ( 0,  1),
+                     SouthWest => (-1,  1),
+                     West      => (-1,  0),
+                     NorthWest => (-1, -1) }
+    }
+}
+
+impl std::ops::Not for Direction8 {
+
+    type Output = Direction8;
+
+    fn not(self) -> Direction8 { Self::ALL[(self.to_index() + 4) % 8] }
+}
+
+impl From<Direction> for Direction8 {
+
+    fn from(direction: Direction) -> Self {
+
+        match direction { Up    => Direction8::North,
+                          Down  => Direction8::South,
+                          Left  => Direction8::West,
+                          Right => Direction8::East }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Coord { pub x: usize, pub y: usize }
 
-impl Coord { pub fn new(x: usize, y: usize) -> Self { Self { x, y } } }
+impl Coord {
+
+    pub fn new(x: usize, y: usize) -> Self { Self { x, y } }
+
+    pub fn offset_by(self, (x, y): (isize, isize)) -> Option<Self> {
+
+        self.x.checked_add_signed(x)
+              .and_then(|x| self.y.checked_add_signed(y)
+                                  .map(|y| Coord { x, y }))
+    }
+
+    pub fn manhattan(self, other: Coord) -> usize {
+
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Coord) -> usize {
+
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
 
 impl std::fmt::Display for Coord {
 
@@ -80,14 +183,18 @@ impl std::ops::Add<Direction> for Coord {
     type Output = Option<Self>;
 
     fn add(self, direction: Direction) -> Option<Self> {
-        
-        let (x, y) = match direction { Up    => ( 0, -1),
-                                       Down  => ( 0,  1),
-                                       Left  => (-1,  0),
-                                       Right => ( 1,  0)};
-        self.x.checked_add_signed(x)
-              .and_then(|x| self.y.checked_add_signed(y)
-                                  .map(|y| Coord { x, y }))
+
+        self.offset_by(direction.to_offset())
+    }
+}
+
+impl std::ops::Add<Direction8> for Coord {
+
+    type Output = Option<Self>;
+
+    fn add(self, direction: Direction8) -> Option<Self> {
+
+        self.offset_by(direction.to_offset())
     }
 }
 
@@ -106,11 +213,23 @@ impl std::ops::AddAssign<Direction> for Option<Coord> {
     }
 }
 
-#[derive(Clone, Hash)]
+#[derive(Clone, Hash, PartialEq, Eq)]
 pub struct Grid<T> { cells: Vec<Vec<T>> }
 
+pub trait GridCell: Sized {
+
+    fn from_char(char: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
 impl<T> Grid<T> {
 
+    pub fn new(width: usize, height: usize, value: T) -> Self where T: Clone {
+
+        Self { cells: vec![vec![value; width]; height] }
+    }
+
     pub fn parse(text: &str, parse_char: impl Fn(char) -> Option<T>)
         -> Result<Self, String> {
 
@@ -130,6 +249,26 @@ impl<T> Grid<T> {
         Ok(Self { cells })
     }
 
+    pub fn parse_cells(text: &str) -> Result<Self, String> where T: GridCell {
+
+        Self::parse(text, T::from_char)
+    }
+
+    pub fn render(&self, to_char: impl Fn(Coord, &T) -> char) -> String {
+
+        let render_row = |(y, v): (usize, &Vec<T>)|
+            v.iter()
+             .enumerate()
+             .map(|(x, t)| to_char(Coord::new(x, y), t))
+             .collect::<String>();
+
+        self.cells.iter()
+                  .enumerate()
+                  .map(render_row)
+                  .collect::<Vec<_>>()
+                  .join("\n")
+    }
+
     pub fn width(&self) -> usize {
         
         match &self.cells[..] { [v, ..] => v.len(), [] => 0 }
@@ -142,7 +281,7 @@ impl<T> Grid<T> {
         coord.x < self.width() && coord.y < self.height()
     }
 
-    pub fn get_at(&mut self, coord: Option<Coord>) -> Option<&T> {
+    pub fn get_at(&self, coord: Option<Coord>) -> Option<&T> {
 
         coord.and_then(|c| self.cells.get(c.y).and_then(|v| v.get(c.x)))
     }
@@ -184,23 +323,65 @@ impl<T> Grid<T> {
         })
     }
 
-    pub fn iter(&self) -> GridIterator<T> {
+    pub fn iter(&self) -> GridIterator<'_, T> {
 
         GridIterator { grid: self, x: 0, y: 0 }
     }
 }
 
-impl<T: std::hash::Hash> Grid<T> {
+#[derive(Clone)] // Clones share their keys
+pub struct Zobrist { keys: Rc<[u64]>, variants: usize, width: usize, hash: u64 }
+
+impl Zobrist {
+
+    pub fn new<T>(grid: &Grid<T>,
+                  variants: usize,
+                  to_variant: impl Fn(&T) -> usize) -> Self {
+
+        let mut seed = 0x9e37_79b9_7f4a_7c15u64;
+
+        let mut next_key = || { // splitmix64
+
+            seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+            let key = (seed ^ (seed >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            let key = (key ^ (key >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+            key ^ (key >> 31)
+        };
+
+        let keys = (0 .. grid.width() * grid.height() * variants)
+                   .map(|_| next_key())
+                   .collect();
+
+        let mut zobrist = Self { keys, variants, width: grid.width(), hash: 0 };
 
-    pub fn get_hash(&self) -> u64 {
+        for (coord, value) in grid.iter() {
 
-        use std::hash::Hasher;
+            zobrist.hash ^= zobrist.key(coord, to_variant(value));
+        }
+
+        zobrist
+    }
+
+    fn key(&self, coord: Coord, variant: usize) -> u64 {
+
+        self.keys[(coord.y * self.width + coord.x) * self.variants + variant]
+    }
 
-        let mut hasher = std::hash::DefaultHasher::new();
+    pub fn update(&mut self, coord: Coord, from: usize, to: usize) {
 
-        std::hash::Hash::hash(self, &mut hasher);
+        self.hash ^= self.key(coord, from) ^ self.key(coord, to);
+    }
+
+    pub fn hash(&self) -> u64 { self.hash }
+}
+
+impl<T: GridCell> std::fmt::Display for Grid<T> {
+
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
 
-        hasher.finish()
+        formatter.write_str(&self.render(|_, v| v.to_char()))
     }
 }
 
@@ -0,0 +1,229 @@
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord3 { pub x: usize, pub y: usize, pub z: usize }
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Offset3 { pub x: isize, pub y: isize, pub z: isize }
+
+impl Coord3 {
+
+    pub fn new(x: usize, y: usize, z: usize) -> Self { Self { x, y, z } }
+}
+
+impl std::fmt::Display for Coord3 {
+
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+
+        write!(formatter, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl std::fmt::Debug for Coord3 {
+
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+
+        std::fmt::Display::fmt(self, formatter)
+    }
+}
+
+impl From<(usize, usize, usize)> for Coord3 {
+
+    fn from((x, y, z): (usize, usize, usize)) -> Self { Self { x, y, z } }
+}
+
+impl std::ops::Add<Offset3> for Coord3 {
+
+    type Output = Option<Self>;
+
+    fn add(self, offset: Offset3) -> Option<Self> {
+
+        Some(Coord3 { x: self.x.checked_add_signed(offset.x)?,
+                      y: self.y.checked_add_signed(offset.y)?,
+                      z: self.z.checked_add_signed(offset.z)? })
+    }
+}
+
+impl std::ops::Add<Offset3> for Option<Coord3> {
+
+    type Output = Self;
+
+    fn add(self, offset: Offset3) -> Self { self? + offset }
+}
+
+impl std::ops::AddAssign<Offset3> for Option<Coord3> {
+
+    fn add_assign(&mut self, offset: Offset3) {
+
+        if let Some(c) = *self { *self = c + offset; }
+    }
+}
+
+impl std::ops::Sub<Coord3> for Coord3 {
+
+    type Output = Offset3;
+
+    fn sub(self, coord: Self) -> Offset3 {
+
+        Offset3 { x: self.x as isize - coord.x as isize,
+                  y: self.y as isize - coord.y as isize,
+                  z: self.z as isize - coord.z as isize }
+    }
+}
+
+impl Offset3 {
+
+    pub const FACES: [Offset3; 6] = [Offset3 { x: -1, y:  0, z:  0 },
+                                     Offset3 { x:  1, y:  0, z:  0 },
+                                     Offset3 { x:  0, y: -1, z:  0 },
+                                     Offset3 { x:  0, y:  1, z:  0 },
+                                     Offset3 { x:  0, y:  0, z: -1 },
+                                     Offset3 { x:  0, y:  0, z:  1 }];
+
+    pub fn new(x: isize, y: isize, z: isize) -> Self { Self { x, y, z } }
+
+    pub fn around() -> impl Iterator<Item = Offset3> { // All 26 neighbours
+
+        (0 .. 27).map(|i| Offset3::new(i % 3 - 1, i / 3 % 3 - 1, i / 9 - 1))
+                 .filter(|&o| o != Offset3::default())
+    }
+
+    pub fn manhattan(self) -> usize {
+
+        self.x.unsigned_abs() + self.y.unsigned_abs() + self.z.unsigned_abs()
+    }
+
+    pub fn chebyshev(self) -> usize {
+
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+                             .max(self.z.unsigned_abs())
+    }
+}
+
+impl std::ops::Add<Offset3> for Offset3 {
+
+    type Output = Self;
+
+    fn add(self, offset: Self) -> Self {
+
+        Self { x: self.x + offset.x,
+               y: self.y + offset.y,
+               z: self.z + offset.z }
+    }
+}
+
+impl std::ops::Sub<Offset3> for Offset3 {
+
+    type Output = Self;
+
+    fn sub(self, offset: Self) -> Self {
+
+        Self { x: self.x - offset.x,
+               y: self.y - offset.y,
+               z: self.z - offset.z }
+    }
+}
+
+impl std::ops::Neg for Offset3 {
+
+    type Output = Self;
+
+    fn neg(self) -> Self { Self { x: -self.x, y: -self.y, z: -self.z } }
+}
+
+impl std::ops::Mul<isize> for Offset3 {
+
+    type Output = Self;
+
+    fn mul(self, scalar: isize) -> Self {
+
+        Self { x: self.x * scalar, y: self.y * scalar, z: self.z * scalar }
+    }
+}
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct Grid3<T> { cells: Vec<T>, width: usize, height: usize }
+
+impl<T> Grid3<T> {
+
+    pub fn new(width: usize, height: usize, depth: usize, value: T) -> Self
+        where T: Clone {
+
+        Self { cells: vec![value; width * height * depth], width, height }
+    }
+
+    pub fn width(&self) -> usize { self.width }
+
+    pub fn height(&self) -> usize { self.height }
+
+    pub fn depth(&self) -> usize {
+
+        match self.width * self.height { 0 => 0, a => self.cells.len() / a }
+    }
+
+    pub fn in_bounds(&self, coord: Coord3) -> bool {
+
+        coord.x < self.width() && coord.y < self.height()
+                               && coord.z < self.depth()
+    }
+
+    fn index(&self, coord: Coord3) -> Option<usize> {
+
+        self.in_bounds(coord).then(|| (coord.z * self.height + coord.y)
+                                      * self.width + coord.x)
+    }
+
+    pub fn get_at(&self, coord: Option<Coord3>) -> Option<&T> {
+
+        coord.and_then(|c| self.index(c)).map(|i| &self.cells[i])
+    }
+
+    pub fn get_at_mut(&mut self, coord: Option<Coord3>) -> Option<&mut T> {
+
+        coord.and_then(|c| self.index(c)).map(|i| &mut self.cells[i])
+    }
+
+    pub fn neighbours_6(&self, coord: Coord3)
+        -> impl Iterator<Item = Coord3> + '_ {
+
+        Offset3::FACES.into_iter()
+                      .filter_map(move |o| coord + o)
+                      .filter(|&c| self.in_bounds(c))
+    }
+
+    pub fn neighbours_26(&self, coord: Coord3)
+        -> impl Iterator<Item = Coord3> + '_ {
+
+        Offset3::around().filter_map(move |o| coord + o)
+                         .filter(|&c| self.in_bounds(c))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord3, &T)> {
+
+        let (width, height) = (self.width, self.height);
+
+        self.cells.iter().enumerate().map(move |(i, v)| {
+
+            let coord = Coord3 { x: i % width,
+                                 y: i / width % height,
+                                 z: i / width / height };
+            (coord, v)
+        })
+    }
+}
+
+#[test]
+fn test_neighbours() {
+
+    let grid = Grid3::new(3, 3, 3, ());
+
+    let count = |c, n: usize| match n {
+        6 => grid.neighbours_6(Coord3::from(c)).count(),
+        _ => grid.neighbours_26(Coord3::from(c)).count()
+    };
+
+    assert_eq!([count((1, 1, 1), 6), count((0, 0, 0), 6)], [6, 3]);
+
+    assert_eq!([count((1, 1, 1), 26), count((0, 0, 0), 26)], [26, 7]);
+
+    assert_eq!(grid.iter().nth(13).unwrap().0, Coord3::new(1, 1, 1));
+}
@@ -0,0 +1,149 @@
+
+use super::grid::Turn;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexDirection { East, NorthEast, NorthWest, West, SouthWest, SouthEast }
+
+use HexDirection::*;
+
+impl HexDirection {
+
+    pub const ALL: [HexDirection; 6] =
+        [East, NorthEast, NorthWest, West, SouthWest, SouthEast];
+
+    pub fn to_index(self) -> usize {
+
+        Self::ALL.iter().position(|&d| d == self).unwrap()
+    }
+
+    pub fn turned(self, turn: Turn) -> HexDirection { // By 60 degrees
+
+        Self::ALL[match turn { Turn::Left  => self.to_index() + 1,
+                               Turn::Right => self.to_index() + 5 } % 6]
+    }
+
+    pub fn to_hex(self) -> Hex { // Pointy topped, so r runs south-east
+
+        let (q, r) = match self { East      => ( 1,  0),
+                                  NorthEast => ( 1, -1),
+                                  NorthWest => ( 0, -1),
+                                  West      => (-1,  0),
+                                  SouthWest => (-1,  1),
+                                  SouthEast => ( 0,  1) };
+        Hex { q, r }
+    }
+}
+
+impl std::ops::Not for HexDirection {
+
+    type Output = HexDirection;
+
+    fn not(self) -> HexDirection { Self::ALL[(self.to_index() + 3) % 6] }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hex { pub q: isize, pub r: isize } // Axial
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cube { pub q: isize, pub r: isize, pub s: isize } // q + r + s = 0
+
+impl Hex {
+
+    pub fn new(q: isize, r: isize) -> Self { Self { q, r } }
+
+    pub fn neighbours(self) -> [Hex; 6] { HexDirection::ALL.map(|d| self + d) }
+
+    pub fn length(self) -> usize { Cube::from(self).length() }
+
+    pub fn distance(self, other: Hex) -> usize { (self - other).length() }
+}
+
+impl Cube {
+
+    pub fn length(self) -> usize {
+
+        (self.q.unsigned_abs() + self.r.unsigned_abs() + self.s.unsigned_abs())
+        / 2
+    }
+
+    pub fn distance(self, other: Cube) -> usize {
+
+        Hex::from(self).distance(Hex::from(other))
+    }
+}
+
+impl From<Hex> for Cube {
+
+    fn from(hex: Hex) -> Self { Self { q: hex.q, r: hex.r, s: -hex.q - hex.r } }
+}
+
+impl From<Cube> for Hex {
+
+    fn from(cube: Cube) -> Self { Self { q: cube.q, r: cube.r } }
+}
+
+impl std::ops::Add<Hex> for Hex {
+
+    type Output = Self;
+
+    fn add(self, hex: Self) -> Self {
+
+        Self { q: self.q + hex.q, r: self.r + hex.r }
+    }
+}
+
+impl std::ops::Sub<Hex> for Hex {
+
+    type Output = Self;
+
+    fn sub(self, hex: Self) -> Self {
+
+        Self { q: self.q - hex.q, r: self.r - hex.r }
+    }
+}
+
+impl std::ops::Mul<isize> for Hex {
+
+    type Output = Self;
+
+    fn mul(self, scalar: isize) -> Self {
+
+        Self { q: self.q * scalar, r: self.r * scalar }
+    }
+}
+
+impl std::ops::Add<HexDirection> for Hex {
+
+    type Output = Self;
+
+    fn add(self, direction: HexDirection) -> Self { self + direction.to_hex() }
+}
+
+impl std::ops::AddAssign<HexDirection> for Hex {
+
+    fn add_assign(&mut self, direction: HexDirection) {
+
+        *self = *self + direction;
+    }
+}
+
+#[test]
+fn test_distance() {
+
+    let walk = |directions: &[HexDirection]|
+        directions.iter().fold(Hex::default(), |h, &d| h + d).length();
+
+    assert_eq!(walk(&[East, East, NorthEast]), 3);
+
+    assert_eq!(walk(&[NorthEast, NorthEast, SouthWest, SouthWest]), 0);
+
+    assert_eq!(walk(&[NorthEast, SouthEast]), 1);
+
+    assert_eq!(walk(&[NorthWest, NorthWest, West]), 3);
+
+    assert!(Hex::default().neighbours().iter().all(|h| h.length() == 1));
+
+    assert_eq!(East.turned(Turn::Left).turned(Turn::Right), East);
+
+    assert_eq!(!NorthEast, SouthWest);
+}
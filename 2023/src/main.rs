@@ -38,6 +38,34 @@ mod day_17;
 
 mod day_18;
 
+mod cards;
+
+mod checked;
+
+mod compress;
+
+mod cycle;
+
+mod geometry;
+
+mod graph;
+
 mod grid;
 
+mod grid_3d;
+
+mod hex;
+
+mod memo;
+
+mod number;
+
+mod points;
+
+mod ranges;
+
+mod search;
+
+mod sequence;
+
 fn main() {}
\ No newline at end of file
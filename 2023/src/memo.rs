@@ -0,0 +1,109 @@
+
+use std::{ collections::HashMap, hash::{ BuildHasherDefault, Hash, Hasher } };
+
+#[derive(Default)]
+pub struct FxHasher { hash: u64 } // As used by rustc, fast but not DoS safe
+
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+impl FxHasher {
+
+    fn add(&mut self, word: u64) {
+
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+
+    fn write(&mut self, bytes: &[u8]) {
+
+        let mut chunks = bytes.chunks_exact(8);
+
+        for chunk in &mut chunks {
+
+            self.add(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+
+        for &byte in chunks.remainder() { self.add(byte as u64); }
+    }
+
+    fn write_u8(&mut self, value: u8) { self.add(value as u64); }
+
+    fn write_u32(&mut self, value: u32) { self.add(value as u64); }
+
+    fn write_u64(&mut self, value: u64) { self.add(value); }
+
+    fn write_usize(&mut self, value: usize) { self.add(value as u64); }
+
+    fn finish(&self) -> u64 { self.hash }
+}
+
+pub type FxHashMap<K, V> = HashMap<K, V, BuildHasherDefault<FxHasher>>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats { pub hits: usize, pub misses: usize }
+
+pub struct Memo<K, V> { cache: FxHashMap<K, V>, stats: CacheStats }
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+
+    pub fn new() -> Self {
+
+        Self { cache: FxHashMap::default(), stats: CacheStats::default() }
+    }
+
+    // The cache isn't borrowed while computing, so compute can recurse
+    pub fn get_or_insert_with(&mut self,
+                              key: K,
+                              compute: impl FnOnce(&mut Self) -> V) -> V {
+
+        if let Some(value) = self.cache.get(&key) {
+
+            self.stats.hits += 1;
+
+            return value.clone();
+        }
+
+        self.stats.misses += 1;
+
+        let value = compute(self);
+
+        self.cache.insert(key, value.clone());
+
+        value
+    }
+
+    pub fn stats(&self) -> CacheStats { self.stats }
+
+    pub fn len(&self) -> usize { self.cache.len() }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+
+    fn default() -> Self { Self::new() }
+}
+
+#[test]
+fn test_memo() {
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+
+        if n < 2 { return n; }
+
+        memo.get_or_insert_with(n, |memo| fibonacci(memo, n - 1)
+                                        + fibonacci(memo, n - 2))
+    }
+
+    let mut memo = Memo::new();
+
+    assert_eq!(fibonacci(&mut memo, 90), 2_880_067_194_370_816_120);
+
+    assert_eq!(memo.stats(), CacheStats { hits: 87, misses: 89 });
+
+    assert_eq!(memo.len(), 89);
+
+    fibonacci(&mut memo, 90);
+
+    assert_eq!(memo.stats().hits, 88);
+}
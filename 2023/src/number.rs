@@ -0,0 +1,176 @@
+
+pub fn gcd(a: u64, b: u64) -> u64 { if b == 0 { a } else { gcd(b, a % b) } }
+
+pub fn lcm(a: u64, b: u64) -> Option<u64> { // None if it overflows
+
+    if a == 0 || b == 0 { return Some(0); }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+pub fn gcd_all(values: impl IntoIterator<Item = u64>) -> u64 {
+
+    values.into_iter().fold(0, gcd)
+}
+
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+
+    values.into_iter().try_fold(1, lcm)
+}
+
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) { // a.x + b.y = g
+
+    if b == 0 { return (a.abs(), a.signum(), 0); }
+
+    let (g, x, y) = extended_gcd(b, a.rem_euclid(b));
+
+    (g, y, x - a.div_euclid(b) * y)
+}
+
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+
+    let (g, x, _) = extended_gcd(a as i128, modulus as i128);
+
+    (g == 1).then(|| x.rem_euclid(modulus as i128) as i64)
+}
+
+// Solves x = residue mod modulus for every pair at once, where the moduli
+// needn't be coprime. Gives the least non negative x and the lcm of the
+// moduli, or None if the congruences contradict each other, a modulus
+// isn't positive, or the result overflows
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>)
+    -> Option<(i64, i64)> {
+
+    let mut solution = (0i128, 1i128);
+
+    for (residue, modulus) in congruences {
+
+        if modulus <= 0 { return None; }
+
+        let (r1, m1) = solution;
+        let (r2, m2) = ((residue as i128).rem_euclid(modulus as i128),
+                        modulus as i128);
+
+        let (g, p, _) = extended_gcd(m1, m2);
+
+        if (r2 - r1) % g != 0 { return None; }
+
+        let lcm = (m1 / g).checked_mul(m2)?;
+
+        let step = ((r2 - r1) / g).checked_mul(p)?.rem_euclid(m2 / g);
+
+        solution = (m1.checked_mul(step)?.checked_add(r1)?, lcm);
+    }
+
+    let (residue, modulus) = solution;
+
+    Some((i64::try_from(residue).ok()?, i64::try_from(modulus).ok()?))
+}
+
+pub fn isqrt(n: u128) -> u128 { // Newton's method, rounding down
+
+    if n < 2 { return n; }
+
+    let mut x = 1 << (n.ilog2() / 2 + 1); // Above the root
+
+    loop {
+
+        let next = (x + n / x) / 2;
+
+        if next >= x { return x; }
+
+        x = next;
+    }
+}
+
+// Counts the integers x with x^2 - sum.x + product < 0, being those
+// strictly between two roots with that sum and product. Exact for any
+// inputs, as no intermediate value is allowed to overflow
+pub fn count_strictly_between_roots(sum: u128, product: u128) -> u128 {
+
+    // A product too big to hold is certainly bigger than the one given
+    let below = |x: u128| x.checked_mul(sum - x).is_none_or(|v| v > product);
+
+    let middle = sum / 2; // Where x.(sum - x) peaks
+
+    if !below(middle) { return 0; }
+
+    let low = match sum.checked_mul(sum) {
+
+        // Within one of the lower root, then nudged up past it exactly.
+        // The discriminant is positive, so 4.product is below the square
+        Some(square) => {
+
+            let mut low = (sum - isqrt(square - 4 * product)) / 2;
+
+            while !below(low) { low += 1; }
+
+            low
+        },
+
+        None => { // Too big to square, so bisect for the first one below
+
+            let (mut above, mut low) = (0, middle);
+
+            while low - above > 1 {
+
+                let x = (above + low) / 2;
+
+                if below(x) { low = x; } else { above = x; }
+            }
+
+            low
+        }
+    };
+
+    sum - low - low + 1 // Symmetric about sum / 2
+}
+
+#[test]
+fn test_number() {
+
+    assert_eq!(gcd_all([12, 18, 30]), 6);
+
+    assert_eq!(lcm_all([4, 6, 10]), Some(60));
+
+    assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+
+    let (g, x, y) = extended_gcd(240, 46);
+
+    assert_eq!((g, 240 * x + 46 * y), (2, 2));
+
+    assert_eq!(mod_inverse(3, 11), Some(4));
+
+    assert_eq!(mod_inverse(4, 10), None);
+
+    assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+
+    assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+
+    assert_eq!(crt([(1, 4), (2, 6)]), None);
+
+    assert_eq!(crt([(-1, 1_000_000_007), (0, 998_244_353)]), // Needs i128
+               Some((993_328_913_953_302_348, 998_244_359_987_710_471)));
+
+    let primes = [999_999_937, 999_999_929, 999_999_893, 999_999_883,
+                  999_999_797];
+
+    assert_eq!(crt(primes.map(|p| (1, p)).into_iter().take(3)), None); // i64
+
+    assert_eq!(crt(primes.map(|p| (1, p))), None); // Past even i128
+
+    assert_eq!(crt([(1, 3), (0, 0)]), None);
+}
+
+#[test]
+fn test_isqrt() {
+
+    for n in (0 .. 10_000).chain([u64::MAX as u128, u128::MAX]) {
+
+        let root = isqrt(n);
+
+        assert!(root * root <= n);
+
+        assert!((root + 1).checked_mul(root + 1).is_none_or(|s| s > n));
+    }
+}
@@ -0,0 +1,63 @@
+
+use super::grid::Coord;
+
+// Sum of b - a over every sorted pair of values, with each unoccupied
+// value between two points taking up `factor` places. Any factor works,
+// with 1 leaving the values as they are and 0 squeezing the gaps out
+pub fn pairwise_axis_sum(values: impl IntoIterator<Item = usize>,
+                         factor: usize) -> usize {
+
+    let mut values = values.into_iter().collect::<Vec<_>>();
+
+    values.sort_unstable();
+
+    let count = values.len();
+
+    // Each gap is crossed by every pair with one point either side
+    values.windows(2)
+          .enumerate()
+          .map(|(i, w)| {
+
+              let empty = (w[1] - w[0]).saturating_sub(1);
+
+              let span = if w[0] == w[1] { 0 } else { 1 + empty * factor };
+
+              span * (i + 1) * (count - i - 1)
+          })
+          .sum()
+}
+
+pub fn pairwise_manhattan_sum(points: &[Coord], factors: (usize, usize))
+    -> usize {
+
+    pairwise_axis_sum(points.iter().map(|c| c.x), factors.0)
+    + pairwise_axis_sum(points.iter().map(|c| c.y), factors.1)
+}
+
+#[test]
+fn test_pairwise_manhattan_sum() {
+
+    let points = [(0, 0), (3, 1), (3, 4), (7, 1), (1, 1)]
+                 .map(Coord::from);
+
+    let brute_force = points.iter()
+                            .enumerate()
+                            .flat_map(|(i, a)| points[i + 1 ..]
+                                               .iter()
+                                               .map(|b| a.manhattan(*b)))
+                            .sum::<usize>();
+
+    assert_eq!(pairwise_manhattan_sum(&points, (1, 1)), brute_force);
+
+    // Empty columns 2, 4, 5 and 6, and rows 2 and 3, between the points
+    let expanded = [(0, 0), (7, 1), (7, 22), (23, 1), (1, 1)]
+                   .map(Coord::from);
+
+    assert_eq!(pairwise_manhattan_sum(&points, (5, 10)),
+               pairwise_manhattan_sum(&expanded, (1, 1)));
+
+    let squeezed = [(0, 0), (2, 1), (2, 2), (3, 1), (1, 1)].map(Coord::from);
+
+    assert_eq!(pairwise_manhattan_sum(&points, (0, 0)),
+               pairwise_manhattan_sum(&squeezed, (1, 1)));
+}
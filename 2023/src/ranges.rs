@@ -0,0 +1,294 @@
+
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> { ranges: Vec<Range<T>> } // Sorted, disjoint, apart
+
+impl<T: Copy + Ord> RangeSet<T> {
+
+    pub fn new() -> Self { Self { ranges: Vec::new() } }
+
+    pub fn ranges(&self) -> &[Range<T>] { &self.ranges }
+
+    pub fn is_empty(&self) -> bool { self.ranges.is_empty() }
+
+    pub fn min(&self) -> Option<T> { self.ranges.first().map(|r| r.start) }
+
+    pub fn contains(&self, value: &T) -> bool {
+
+        let index = self.ranges.partition_point(|r| r.end <= *value);
+
+        self.ranges.get(index).is_some_and(|r| r.contains(value))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+
+        let (mut a, mut b) = (self.ranges.iter().peekable(),
+                              other.ranges.iter().peekable());
+
+        let mut ranges = Vec::new();
+
+        while let Some((x, y)) = a.peek().zip(b.peek()) {
+
+            let overlap = x.start.max(y.start) .. x.end.min(y.end);
+
+            if overlap.start < overlap.end { ranges.push(overlap); }
+
+            if x.end < y.end { a.next(); } else { b.next(); }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+
+        let mut ranges = Vec::new();
+
+        for range in &self.ranges {
+
+            let mut start = range.start;
+
+            let first = other.ranges.partition_point(|r| r.end <= start);
+
+            let cuts = other.ranges[first ..]
+                            .iter()
+                            .take_while(|r| r.start < range.end);
+
+            for cut in cuts {
+
+                if start < cut.start { ranges.push(start .. cut.start); }
+
+                start = start.max(cut.end);
+            }
+
+            if start < range.end { ranges.push(start .. range.end); }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl<T: Copy + Ord> Default for RangeSet<T> {
+
+    fn default() -> Self { Self::new() }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+
+        let mut sorted = iter.into_iter()
+                             .filter(|r| r.start < r.end)
+                             .collect::<Vec<_>>();
+
+        sorted.sort_by_key(|r| r.start);
+
+        let mut ranges = Vec::<Range<T>>::new();
+
+        for range in sorted {
+
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end =>
+                    last.end = last.end.max(range.end),
+                _ => ranges.push(range)
+            }
+        }
+
+        Self { ranges }
+    }
+}
+
+pub struct MapItem { pub source: Range<i64>, pub target_start: i64 }
+
+// Shifts values in each piece's range by its offset, and leaves the rest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseLinearMap { pieces: Vec<(Range<i64>, i64)> }
+
+const ALL: Range<i64> = i64::MIN .. i64::MAX;
+
+impl PiecewiseLinearMap {
+
+    pub fn identity() -> Self { Self { pieces: Vec::new() } }
+
+    pub fn from_items<'a>(items: impl IntoIterator<Item = &'a MapItem>)
+        -> Self { // Earlier items win where they overlap
+
+        let mut covered = RangeSet::new();
+
+        let mut pieces = Vec::new();
+
+        for item in items {
+
+            let source = RangeSet::from_iter([item.source.clone()]);
+
+            let offset = item.target_start - item.source.start;
+
+            for range in source.difference(&covered).ranges() {
+
+                pieces.push((range.clone(), offset));
+            }
+
+            covered = covered.union(&source);
+        }
+
+        Self::from_pieces(pieces)
+    }
+
+    fn from_pieces(mut pieces: Vec<(Range<i64>, i64)>) -> Self {
+
+        pieces.retain(|(r, o)| *o != 0 && r.start < r.end);
+
+        pieces.sort_by_key(|(r, _)| r.start);
+
+        let mut merged = Vec::<(Range<i64>, i64)>::new();
+
+        for (range, offset) in pieces {
+
+            match merged.last_mut() {
+                Some((last, o)) if last.end == range.start && *o == offset =>
+                    last.end = range.end,
+                _ => merged.push((range, offset))
+            }
+        }
+
+        Self { pieces: merged }
+    }
+
+    fn segments(&self) -> Vec<(Range<i64>, i64)> { // Covering everything
+
+        let sources = self.pieces.iter().map(|(r, _)| r.clone()).collect();
+
+        let gaps = RangeSet::from_iter([ALL]).difference(&sources);
+
+        let mut segments = self.pieces.clone();
+
+        segments.extend(gaps.ranges.into_iter().map(|r| (r, 0)));
+
+        segments.sort_by_key(|(r, _)| r.start);
+
+        segments
+    }
+
+    pub fn apply(&self, value: i64) -> i64 {
+
+        let index = self.pieces.partition_point(|(r, _)| r.end <= value);
+
+        match self.pieces.get(index) {
+            Some((range, offset)) if range.contains(&value) => value + offset,
+            _ => value
+        }
+    }
+
+    pub fn apply_set(&self, set: &RangeSet<i64>) -> RangeSet<i64> {
+
+        self.segments()
+            .into_iter()
+            .flat_map(|(range, offset)|
+                set.intersection(&RangeSet::from_iter([range]))
+                   .ranges
+                   .into_iter()
+                   .map(move |r| r.start + offset .. r.end + offset))
+            .collect()
+    }
+
+    pub fn compose(&self, then: &Self) -> Self { // self, then then
+
+        let then_segments = then.segments();
+
+        let mut pieces = Vec::new();
+
+        for (range, offset) in self.segments() {
+
+            let image = range.start + offset .. range.end + offset;
+
+            for (then_range, then_offset) in &then_segments {
+
+                let start = image.start.max(then_range.start);
+                let end = image.end.min(then_range.end);
+
+                if start < end {
+
+                    pieces.push((start - offset .. end - offset,
+                                 offset + then_offset));
+                }
+            }
+        }
+
+        Self::from_pieces(pieces)
+    }
+
+    pub fn invert(&self) -> Option<Self> { // None unless one to one
+
+        let mut images = self.segments()
+                             .into_iter()
+                             .map(|(r, o)| (r.start + o .. r.end + o, -o))
+                             .collect::<Vec<_>>();
+
+        images.sort_by_key(|(r, _)| r.start);
+
+        if images.windows(2).any(|w| w[0].0.end != w[1].0.start) {
+
+            return None;
+        }
+
+        Some(Self::from_pieces(images))
+    }
+}
+
+#[test]
+fn test_range_set() {
+
+    let a = RangeSet::from_iter([0 .. 5, 3 .. 8, 10 .. 12, 12 .. 14, 20 .. 20]);
+
+    assert_eq!(a.ranges(), [0 .. 8, 10 .. 14]);
+
+    let b = RangeSet::from_iter([4 .. 11, 13 .. 16, 20 .. 30]);
+
+    assert_eq!(a.union(&b).ranges(), [0 .. 16, 20 .. 30]);
+
+    assert_eq!(a.intersection(&b).ranges(), [4 .. 8, 10 .. 11, 13 .. 14]);
+
+    assert_eq!(a.difference(&b).ranges(), [0 .. 4, 11 .. 13]);
+
+    assert!(a.contains(&13) && !a.contains(&8));
+}
+
+#[test]
+fn test_piecewise_linear_map() {
+
+    let seed_to_soil = PiecewiseLinearMap::from_items(
+        &[MapItem { source: 98 .. 100, target_start: 50 },
+          MapItem { source: 50 .. 98, target_start: 52 }]);
+
+    let soil_to_fertilizer = PiecewiseLinearMap::from_items(
+        &[MapItem { source: 15 .. 52, target_start: 0 },
+          MapItem { source: 52 .. 54, target_start: 37 },
+          MapItem { source: 0 .. 15, target_start: 39 }]);
+
+    let composed = seed_to_soil.compose(&soil_to_fertilizer);
+
+    for seed in [79, 14, 55, 13, 99, 0, 200] {
+
+        assert_eq!(composed.apply(seed),
+                   soil_to_fertilizer.apply(seed_to_soil.apply(seed)));
+    }
+
+    let seeds = RangeSet::from_iter([79 .. 93, 55 .. 68]);
+
+    assert_eq!(composed.apply_set(&seeds),
+               soil_to_fertilizer.apply_set(&seed_to_soil.apply_set(&seeds)));
+
+    let inverse = composed.invert().unwrap();
+
+    assert_eq!(inverse.compose(&composed), PiecewiseLinearMap::identity());
+
+    let squashed = PiecewiseLinearMap::from_items(
+        &[MapItem { source: 0 .. 10, target_start: 10 }]);
+
+    assert_eq!(squashed.invert(), None);
+}
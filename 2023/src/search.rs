@@ -0,0 +1,504 @@
+
+use super::grid::Coord;
+
+use std::{ cmp::{ Ordering, Reverse },
+           collections::{ BinaryHeap, HashMap, HashSet } };
+
+pub struct Label<S> { pub cost: usize, pub predecessor: Option<S> }
+
+pub trait Labels<S> {
+
+    fn get(&self, state: &S) -> Option<&Label<S>>;
+
+    fn insert(&mut self, state: S, label: Label<S>);
+}
+
+impl<S: std::hash::Hash + Eq> Labels<S> for HashMap<S, Label<S>> {
+
+    fn get(&self, state: &S) -> Option<&Label<S>> { HashMap::get(self, state) }
+
+    fn insert(&mut self, state: S, label: Label<S>) {
+
+        HashMap::insert(self, state, label);
+    }
+}
+
+pub struct Indexed<S, F> { labels: Vec<Option<Label<S>>>, index: F }
+
+impl<S, F: Fn(&S) -> usize> Indexed<S, F> {
+
+    pub fn new(len: usize, index: F) -> Self {
+
+        Self { labels: (0 .. len).map(|_| None).collect(), index }
+    }
+}
+
+impl<S, F: Fn(&S) -> usize> Labels<S> for Indexed<S, F> {
+
+    fn get(&self, state: &S) -> Option<&Label<S>> {
+
+        self.labels[(self.index)(state)].as_ref()
+    }
+
+    fn insert(&mut self, state: S, label: Label<S>) {
+
+        let index = (self.index)(&state);
+
+        self.labels[index] = Some(label);
+    }
+}
+
+pub trait Queue<T> {
+
+    fn push(&mut self, priority: usize, item: T);
+
+    fn pop(&mut self) -> Option<T>;
+}
+
+struct Prioritised<T> { priority: usize, item: T } // Ordered by priority only
+
+impl<T> PartialEq for Prioritised<T> {
+
+    fn eq(&self, other: &Self) -> bool { self.priority == other.priority }
+}
+
+impl<T> Eq for Prioritised<T> {}
+
+impl<T> PartialOrd for Prioritised<T> {
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Prioritised<T> {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+
+        self.priority.cmp(&other.priority)
+    }
+}
+
+pub struct Heap<T> { heap: BinaryHeap<Reverse<Prioritised<T>>> }
+
+impl<T> Default for Heap<T> {
+
+    fn default() -> Self { Self { heap: BinaryHeap::new() } }
+}
+
+impl<T> Queue<T> for Heap<T> {
+
+    fn push(&mut self, priority: usize, item: T) {
+
+        self.heap.push(Reverse(Prioritised { priority, item }));
+    }
+
+    fn pop(&mut self) -> Option<T> { self.heap.pop().map(|r| r.0.item) }
+}
+
+// Dial's algorithm, for small integer priorities that never drop below
+// the last one popped
+pub struct Buckets<T> { buckets: Vec<Vec<T>>, priority: usize }
+
+impl<T> Default for Buckets<T> {
+
+    fn default() -> Self { Self { buckets: Vec::new(), priority: 0 } }
+}
+
+impl<T> Queue<T> for Buckets<T> {
+
+    fn push(&mut self, priority: usize, item: T) {
+
+        if priority >= self.buckets.len() {
+
+            self.buckets.resize_with(priority + 1, Vec::new);
+        }
+
+        self.buckets[priority.max(self.priority)].push(item);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+
+        while let Some(bucket) = self.buckets.get_mut(self.priority) {
+
+            if let Some(item) = bucket.pop() { return Some(item); }
+
+            self.priority += 1;
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats { pub pushed: usize, pub expanded: usize }
+
+pub struct Search<S, L> { pub labels: L, pub goal: Option<S>, pub stats: Stats }
+
+impl<S: Clone, L: Labels<S>> Search<S, L> {
+
+    pub fn cost(&self, state: &S) -> Option<usize> {
+
+        self.labels.get(state).map(|l| l.cost)
+    }
+
+    pub fn goal_cost(&self) -> Option<usize> {
+
+        self.goal.as_ref().and_then(|g| self.cost(g))
+    }
+
+    pub fn path_to(&self, state: &S) -> Vec<S> { // Empty if unreached
+
+        let mut path = Vec::new();
+
+        let mut next = self.labels.get(state).map(|_| state.clone());
+
+        while let Some(state) = next {
+
+            next = self.labels.get(&state).and_then(|l| l.predecessor.clone());
+
+            path.push(state);
+        }
+
+        path.reverse();
+
+        path
+    }
+}
+
+pub fn manhattan<S>(goal: Coord, position: impl Fn(&S) -> Coord)
+    -> impl Fn(&S) -> usize { // Admissible if every step costs at least 1
+
+    move |state| position(state).manhattan(goal)
+}
+
+pub fn chebyshev<S>(goal: Coord, position: impl Fn(&S) -> Coord)
+    -> impl Fn(&S) -> usize { // Also allowing diagonal steps
+
+    move |state| position(state).chebyshev(goal)
+}
+
+pub fn dijkstra<S, I>(starts: impl IntoIterator<Item = S>,
+                      successors: impl FnMut(&S) -> I,
+                      is_goal: impl FnMut(&S) -> bool)
+    -> Search<S, HashMap<S, Label<S>>>
+    where S: Clone + Eq + std::hash::Hash,
+          I: IntoIterator<Item = (S, usize)> {
+
+    dijkstra_with(HashMap::new(), Heap::default(), starts, successors, is_goal)
+}
+
+pub fn dijkstra_with<S, L, Q, I>(labels: L,
+                                 queue: Q,
+                                 starts: impl IntoIterator<Item = S>,
+                                 successors: impl FnMut(&S) -> I,
+                                 is_goal: impl FnMut(&S) -> bool)
+    -> Search<S, L>
+    where S: Clone,
+          L: Labels<S>,
+          Q: Queue<(usize, S)>,
+          I: IntoIterator<Item = (S, usize)> {
+
+    astar_with(labels, queue, starts, successors, |_| 0, is_goal)
+}
+
+pub fn astar<S, I>(starts: impl IntoIterator<Item = S>,
+                   successors: impl FnMut(&S) -> I,
+                   heuristic: impl FnMut(&S) -> usize,
+                   is_goal: impl FnMut(&S) -> bool)
+    -> Search<S, HashMap<S, Label<S>>>
+    where S: Clone + Eq + std::hash::Hash,
+          I: IntoIterator<Item = (S, usize)> {
+
+    astar_with(HashMap::new(),
+               Heap::default(),
+               starts,
+               successors,
+               heuristic,
+               is_goal)
+}
+
+// The heuristic must never overestimate the remaining cost, and must be
+// consistent (dropping by at most the step cost) for labels to be final
+// when popped
+pub fn astar_with<S, L, Q, I>(mut labels: L,
+                              mut queue: Q,
+                              starts: impl IntoIterator<Item = S>,
+                              mut successors: impl FnMut(&S) -> I,
+                              mut heuristic: impl FnMut(&S) -> usize,
+                              mut is_goal: impl FnMut(&S) -> bool)
+    -> Search<S, L>
+    where S: Clone,
+          L: Labels<S>,
+          Q: Queue<(usize, S)>,
+          I: IntoIterator<Item = (S, usize)> {
+
+    let mut stats = Stats::default();
+
+    for start in starts {
+
+        labels.insert(start.clone(), Label { cost: 0, predecessor: None });
+
+        queue.push(heuristic(&start), (0, start));
+
+        stats.pushed += 1;
+    }
+
+    while let Some((cost, state)) = queue.pop() {
+
+        if labels.get(&state).is_some_and(|l| l.cost < cost) { continue; }
+
+        stats.expanded += 1;
+
+        if is_goal(&state) {
+
+            return Search { labels, goal: Some(state), stats };
+        }
+
+        for (next, step_cost) in successors(&state) {
+
+            let next_cost = cost + step_cost;
+
+            if labels.get(&next).is_some_and(|l| l.cost <= next_cost) {
+
+                continue;
+            }
+
+            let predecessor = Some(state.clone());
+
+            labels.insert(next.clone(), Label { cost: next_cost, predecessor });
+
+            queue.push(next_cost + heuristic(&next), (next_cost, next));
+
+            stats.pushed += 1;
+        }
+    }
+
+    Search { labels, goal: None, stats }
+}
+
+pub struct Dag<S> { // Every predecessor achieving the optimal cost
+
+    pub costs: HashMap<S, usize>,
+    pub predecessors: HashMap<S, Vec<S>>,
+    pub goals: Vec<S>
+}
+
+pub struct Paths<'a, S> { dag: &'a Dag<S>, todo: Vec<Vec<S>> }
+
+impl<S: Clone + Eq + std::hash::Hash> Dag<S> {
+
+    pub fn cost(&self) -> Option<usize> {
+
+        self.goals.first().map(|g| self.costs[g])
+    }
+
+    pub fn count_paths(&self) -> usize { // Step costs must be positive
+
+        let mut order = self.costs.iter().collect::<Vec<_>>();
+
+        order.sort_by_key(|&(_, &cost)| cost);
+
+        let mut counts = HashMap::<&S, usize>::new();
+
+        for (state, _) in order {
+
+            let count = match &self.predecessors[state][..] {
+                [] => 1,
+                predecessors => predecessors.iter().map(|p| counts[p]).sum()
+            };
+
+            counts.insert(state, count);
+        }
+
+        self.goals.iter().map(|g| counts[g]).sum()
+    }
+
+    pub fn nodes(&self) -> HashSet<S> { // On any optimal path
+
+        let mut nodes = HashSet::new();
+
+        let mut todo = self.goals.clone();
+
+        while let Some(state) = todo.pop() {
+
+            if nodes.contains(&state) { continue; }
+
+            todo.extend(self.predecessors[&state].iter().cloned());
+
+            nodes.insert(state);
+        }
+
+        nodes
+    }
+
+    pub fn paths(&self) -> Paths<'_, S> {
+
+        Paths { dag: self, todo: self.goals.iter().map(|g| vec![g.clone()])
+                                                  .collect() }
+    }
+}
+
+impl<S: Clone + Eq + std::hash::Hash> Iterator for Paths<'_, S> {
+
+    type Item = Vec<S>;
+
+    fn next(&mut self) -> Option<Vec<S>> {
+
+        while let Some(mut path) = self.todo.pop() { // Built back to front
+
+            let predecessors = &self.dag.predecessors[path.last().unwrap()];
+
+            if predecessors.is_empty() { path.reverse(); return Some(path); }
+
+            for predecessor in predecessors {
+
+                let mut path = path.clone();
+
+                path.push(predecessor.clone());
+
+                self.todo.push(path);
+            }
+        }
+
+        None
+    }
+}
+
+pub fn shortest_path_dag<S, I>(starts: impl IntoIterator<Item = S>,
+                               mut successors: impl FnMut(&S) -> I,
+                               mut is_goal: impl FnMut(&S) -> bool)
+    -> Dag<S>
+    where S: Clone + Eq + std::hash::Hash,
+          I: IntoIterator<Item = (S, usize)> {
+
+    let mut costs = HashMap::new();
+    let mut predecessors = HashMap::<S, Vec<S>>::new();
+    let mut goals = Vec::new();
+
+    let mut queue = Heap::default();
+
+    for start in starts {
+
+        costs.insert(start.clone(), 0);
+        predecessors.insert(start.clone(), Vec::new());
+
+        queue.push(0, (0, start));
+    }
+
+    while let Some((cost, state)) = queue.pop() {
+
+        if costs[&state] < cost { continue; }
+
+        if goals.first().is_some_and(|g| costs[g] < cost) { break; }
+
+        if is_goal(&state) { goals.push(state); continue; }
+
+        for (next, step_cost) in successors(&state) {
+
+            let next_cost = cost + step_cost;
+
+            match costs.get(&next) {
+
+                Some(&c) if c < next_cost => continue,
+
+                Some(&c) if c == next_cost =>
+                    predecessors.get_mut(&next).unwrap().push(state.clone()),
+
+                _ => {
+                    costs.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+
+                    queue.push(next_cost, (next_cost, next));
+                }
+            }
+        }
+    }
+
+    Dag { costs, predecessors, goals }
+}
+
+#[test]
+fn test_dijkstra() {
+
+    let edges = [(0, 1, 7), (0, 2, 9), (0, 5, 14), (1, 2, 10), (1, 3, 15),
+                 (2, 3, 11), (2, 5, 2), (3, 4, 6), (4, 5, 9)];
+
+    let successors = |&n: &usize| edges.iter()
+                                       .filter(move |e| e.0 == n || e.1 == n)
+                                       .map(move |e| (e.0 + e.1 - n, e.2))
+                                       .collect::<Vec<_>>();
+
+    let search = dijkstra([0], successors, |&n| n == 4);
+
+    assert_eq!(search.goal_cost(), Some(20));
+
+    assert_eq!(search.path_to(&4), [0, 2, 5, 4]);
+
+    let search = dijkstra_with(Indexed::new(6, |&n: &usize| n),
+                               Buckets::default(),
+                               [0],
+                               successors,
+                               |_| false);
+
+    assert_eq!((0 .. 6).map(|n| search.cost(&n).unwrap()).collect::<Vec<_>>(),
+               [0, 7, 9, 20, 20, 11]);
+}
+
+#[test]
+fn test_astar() {
+
+    let (size, start, goal) = (20, Coord::new(0, 10), Coord::new(19, 10));
+
+    let successors = |&c: &Coord|
+        [(1, 0), (0, 1), (-1, 0), (0, -1)]
+            .into_iter()
+            .filter_map(move |o| c.offset_by(o))
+            .filter(|c| c.x < size && c.y < size)
+            .map(|c| (c, 1));
+
+    let plain = dijkstra([start], successors, |&c| c == goal);
+
+    let directed = astar([start],
+                         successors,
+                         manhattan(goal, |&c| c),
+                         |&c| c == goal);
+
+    assert_eq!(plain.goal_cost(), Some(19));
+
+    assert_eq!(directed.goal_cost(), Some(19));
+
+    assert_eq!(directed.path_to(&goal).len(), 20);
+
+    assert!(directed.stats.expanded < plain.stats.expanded / 4);
+}
+
+#[test]
+fn test_shortest_path_dag() {
+
+    let successors = |&(x, y): &(usize, usize)|
+        [(x + 1, y), (x, y + 1)].into_iter()
+                                .filter(|&(x, y)| x < 3 && y < 3)
+                                .map(|s| (s, 1));
+
+    let dag = shortest_path_dag([(0, 0)], successors, |&s| s == (2, 2));
+
+    assert_eq!(dag.cost(), Some(4));
+
+    assert_eq!(dag.count_paths(), 6);
+
+    assert_eq!(dag.nodes().len(), 9);
+
+    let paths = dag.paths().collect::<HashSet<_>>();
+
+    assert_eq!(paths.len(), 6);
+
+    assert!(paths.contains(&vec![(0, 0), (1, 0), (1, 1), (2, 1), (2, 2)]));
+
+    let blocked = |&(x, y): &(usize, usize)|
+        successors(&(x, y)).filter(|&((x, y), _)| (x, y) != (1, 1));
+
+    assert_eq!(shortest_path_dag([(0, 0)], blocked, |&s| s == (2, 2))
+                   .count_paths(), 2);
+}
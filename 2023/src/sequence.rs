@@ -0,0 +1,69 @@
+
+use super::checked::*;
+
+// The least degree polynomial through samples at 0, 1, 2, .., held as
+// the leading finite differences, so p(k) = sum of C(k, j) * differences[j]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial { differences: Vec<i128> }
+
+impl Polynomial {
+
+    pub fn fit(samples: &[i64]) -> Option<Self> { // None unless it settles
+
+        let mut row = samples.iter().map(|&s| s as i128).collect::<Vec<_>>();
+
+        let mut differences = Vec::new();
+
+        while !row.iter().all(|&v| v == 0) {
+
+            if row.len() < 2 { return None; } // Nothing left to confirm it
+
+            differences.push(row[0]);
+
+            row = row.windows(2).map(|w| w[1] - w[0]).collect();
+        }
+
+        Some(Self { differences })
+    }
+
+    pub fn degree(&self) -> usize { self.differences.len().saturating_sub(1) }
+
+    pub fn at(&self, position: i64) -> i128 { // Anywhere, even before 0
+
+        let (k, mut binomial, mut total) = (Checked(position as i128),
+                                            Checked(1), Checked(0));
+
+        for (j, &difference) in self.differences.iter().enumerate() {
+
+            total = total + binomial * Checked(difference);
+
+            let j = Checked(j as i128);
+
+            binomial = binomial * (k - j) / (j + Checked(1)); // Exact
+        }
+
+        total.get()
+    }
+}
+
+#[test]
+fn test_polynomial() {
+
+    let squares = Polynomial::fit(&[1, 4, 9, 16, 25]).unwrap();
+
+    assert_eq!(squares.degree(), 2);
+
+    assert_eq!(squares.at(-3), 4);
+
+    assert_eq!(squares.at(26501365), 26501366 * 26501366);
+
+    let constant = Polynomial::fit(&[7, 7]).unwrap();
+
+    assert_eq!((constant.degree(), constant.at(1000)), (0, 7));
+
+    assert_eq!(Polynomial::fit(&[0, 0, 0]).unwrap().at(5), 0);
+
+    assert_eq!(Polynomial::fit(&[1, 2, 4, 8, 16]), None);
+
+    assert_eq!(Polynomial::fit(&[]).unwrap().at(3), 0);
+}
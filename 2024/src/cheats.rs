@@ -0,0 +1,155 @@
+
+use super::grid::*;
+
+use std::{ collections::BTreeMap, rc::Rc };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cheat { pub from: Offset, pub to: Offset, pub saving: usize }
+
+type Visit = (Offset, usize, usize); // Steps from the start and to the end
+
+// Cheats of up to max_length steps through walls, saving at least one step
+// over the honest route. A track that's a single path is scanned along its
+// length, anything else around each cell in turn
+pub fn cheats<'a>(from_start: &'a Grid<Option<u32>>,
+                  from_end: &'a Grid<Option<u32>>,
+                  max_length: usize) -> Box<dyn Iterator<Item = Cheat> + 'a> {
+
+    let on_path = |(offset, &before): (Offset, &Option<u32>)|
+        Some((offset, before? as usize, (*from_end.get(offset)?)? as usize));
+
+    let mut path = from_start.iter().filter_map(on_path).collect::<Vec<_>>();
+
+    path.sort_by_key(|&(_, before, _)| before);
+
+    let honest_time = path.iter()
+                          .filter(|&&(_, _, after)| after == 0)
+                          .map(|&(_, before, _)| before)
+                          .min()
+                          .unwrap_or(0);
+
+    if is_single_track(&path, honest_time) {
+
+        Box::new(along_track(path.into(), honest_time, max_length))
+    }
+    else {
+
+        Box::new(path.into_iter().flat_map(move |visit|
+            around(visit, from_end, honest_time, max_length)))
+    }
+}
+
+// Each cell follows on from the one before, with no branches or dead ends
+fn is_single_track(path: &[Visit], honest_time: usize) -> bool {
+
+       path.iter()
+           .enumerate()
+           .all(|(i, &(_, before, after))| before == i
+                                           && before + after == honest_time)
+    && path.windows(2).all(|w| w[0].0.manhattan(w[1].0) == 1)
+}
+
+// Each cheat jumps ahead along the track, and as each step moves one cell
+// the scan ahead can skip cells which are too far away to reach yet
+fn along_track(path: Rc<[Visit]>, honest_time: usize, max_length: usize)
+    -> impl Iterator<Item = Cheat> {
+
+    let len = path.len();
+
+    let cheats_from = move |i: usize| {
+
+        let (path, (from, before, _)) = (Rc::clone(&path), path[i]);
+
+        let mut j = i + 1;
+
+        std::iter::from_fn(move || {
+
+            while let Some(&(to, _, after)) = path.get(j) {
+
+                let length = from.manhattan(to);
+
+                j += length.saturating_sub(max_length).max(1);
+
+                let time = before + length + after;
+
+                if length <= max_length && time < honest_time {
+
+                    return Some(Cheat { from, to, saving: honest_time - time });
+                }
+            }
+
+            None
+        })
+    };
+
+    (0 .. len).flat_map(cheats_from)
+}
+
+// Every cell within max_length of the start of the cheat
+fn around((from, before, _): Visit,
+          from_end: &Grid<Option<u32>>,
+          honest_time: usize,
+          max_length: usize) -> impl Iterator<Item = Cheat> + '_ {
+
+    let radius = max_length as isize;
+
+    let diamond = (-radius ..= radius).flat_map(move |y| {
+        let width = radius - y.abs();
+        (-width ..= width).map(move |x| Offset { x, y })
+    });
+
+    diamond.filter_map(move |offset| {
+
+        let to = from + offset;
+
+        let after = (*from_end.get(to)?)? as usize;
+
+        let time = before + from.manhattan(to) + after;
+
+        (time < honest_time)
+            .then(|| Cheat { from, to, saving: honest_time - time })
+    })
+}
+
+pub fn savings_histogram(from_start: &Grid<Option<u32>>,
+                         from_end: &Grid<Option<u32>>,
+                         max_length: usize) -> BTreeMap<usize, usize> {
+
+    let mut histogram = BTreeMap::new();
+
+    for cheat in cheats(from_start, from_end, max_length) {
+
+        *histogram.entry(cheat.saving).or_default() += 1;
+    }
+
+    histogram
+}
+
+#[test]
+fn test_branching_track() {
+
+    let char_grid = Grid::parse("#########\n\
+                                 #S#...#E#\n\
+                                 #.#.#.#.#\n\
+                                 #...#...#\n\
+                                 ###.#####\n\
+                                 ###...###\n\
+                                 #########", Ok).unwrap();
+
+    let find = |target| char_grid.iter().find(|&(_, &c)| c == target).unwrap();
+
+    let grid = char_grid.map(|&c| c != '#');
+
+    let (from_start, from_end) =
+        (distance_field(&grid, [find('S').0], |&b| b),
+         distance_field(&grid, [find('E').0], |&b| b));
+
+    let histogram = |max_length|
+        savings_histogram(&from_start, &from_end, max_length)
+            .into_iter()
+            .collect::<Vec<_>>();
+
+    assert_eq!(histogram(2), [(2, 3), (4, 3)]);
+
+    assert_eq!(histogram(3), [(2, 10), (4, 7)]);
+}
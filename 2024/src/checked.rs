@@ -0,0 +1,147 @@
+
+use std::{ fmt::{ Debug, Display }, ops::{ Add, Div, Mul, Sub } };
+
+// Arithmetic that panics with its operands on overflow, rather than
+// wrapping in release builds. The try_ methods give None instead
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Checked<T>(pub T);
+
+pub trait Primitive: Copy + Debug + Display {
+
+    type Wide: Primitive; // Twice the width, or the same for the widest
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+
+    fn widen(self) -> Self::Wide;
+}
+
+macro_rules! primitive {
+    ($($t:ty => $wide:ty),*) => { $(
+        impl Primitive for $t {
+
+            type Wide = $wide;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_div(self, other: Self) -> Option<Self> {
+                <$t>::checked_div(self, other)
+            }
+
+            fn widen(self) -> $wide { self as $wide }
+        }
+    )* }
+}
+
+primitive!(u32 => u64, u64 => u128, u128 => u128, usize => u128,
+           i32 => i64, i64 => i128, i128 => i128, isize => i128);
+
+impl<T: Primitive> Checked<T> {
+
+    pub fn get(self) -> T { self.0 }
+
+    pub fn widen(self) -> Checked<T::Wide> { Checked(self.0.widen()) }
+
+    pub fn try_add(self, other: Self) -> Option<Self> {
+
+        self.0.checked_add(other.0).map(Checked)
+    }
+
+    pub fn try_sub(self, other: Self) -> Option<Self> {
+
+        self.0.checked_sub(other.0).map(Checked)
+    }
+
+    pub fn try_mul(self, other: Self) -> Option<Self> {
+
+        self.0.checked_mul(other.0).map(Checked)
+    }
+
+    pub fn try_div(self, other: Self) -> Option<Self> {
+
+        self.0.checked_div(other.0).map(Checked)
+    }
+
+    fn expect(result: Option<Self>, a: Self, op: &str, b: Self) -> Self {
+
+        result.unwrap_or_else(|| panic!("Overflow in {} {} {}", a.0, op, b.0))
+    }
+}
+
+impl<T: Primitive> Add for Checked<T> {
+
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+
+        Self::expect(self.try_add(other), self, "+", other)
+    }
+}
+
+impl<T: Primitive> Sub for Checked<T> {
+
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+
+        Self::expect(self.try_sub(other), self, "-", other)
+    }
+}
+
+impl<T: Primitive> Mul for Checked<T> {
+
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+
+        Self::expect(self.try_mul(other), self, "*", other)
+    }
+}
+
+impl<T: Primitive> Div for Checked<T> {
+
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+
+        Self::expect(self.try_div(other), self, "/", other)
+    }
+}
+
+impl<T: Primitive> Display for Checked<T> {
+
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+
+        Display::fmt(&self.0, formatter)
+    }
+}
+
+#[test]
+fn test_checked() {
+
+    let big = Checked(u64::MAX / 2);
+
+    assert_eq!((big + big).get(), u64::MAX - 1);
+
+    assert_eq!(big.try_mul(Checked(3)), None);
+
+    assert_eq!((big.widen() * Checked(3)).get(), (u64::MAX / 2) as u128 * 3);
+
+    assert_eq!(Checked(1usize).try_sub(Checked(2)), None);
+}
+
+#[test]
+#[should_panic(expected = "Overflow in 9223372036854775807 * 3")]
+fn test_checked_overflow() { let _ = Checked(u64::MAX / 2) * Checked(3); }
@@ -0,0 +1,181 @@
+
+use super::grid::*;
+
+pub struct UnionFind { parents: Vec<usize>, sizes: Vec<usize> }
+
+impl UnionFind {
+
+    pub fn new(len: usize) -> Self {
+
+        Self { parents: (0 .. len).collect(), sizes: vec![1; len] }
+    }
+
+    pub fn find(&mut self, mut index: usize) -> usize {
+
+        while self.parents[index] != index {
+
+            self.parents[index] = self.parents[self.parents[index]]; // Halving
+
+            index = self.parents[index];
+        }
+
+        index
+    }
+
+    pub fn union(&mut self, a: usize, b: usize) -> bool { // False if joined
+
+        let (mut a, mut b) = (self.find(a), self.find(b));
+
+        if a == b { return false; }
+
+        if self.sizes[a] < self.sizes[b] { std::mem::swap(&mut a, &mut b); }
+
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+
+        self.find(a) == self.find(b)
+    }
+}
+
+// Given holds(n) for the first n items, true then false as n grows, finds
+// the index of the item that first makes it fail. None if it holds for all
+// of them, or fails before any, as then no item is to blame
+pub fn first_failing(len: usize, mut holds: impl FnMut(usize) -> bool)
+    -> Option<usize> {
+
+    if !holds(0) || holds(len) { return None; }
+
+    let (mut good, mut bad) = (0, len); // holds(good) && !holds(bad)
+
+    while bad - good > 1 {
+
+        let middle = (good + bad) / 2;
+
+        if holds(middle) { good = middle; } else { bad = middle; }
+    }
+
+    Some(bad - 1)
+}
+
+// Closes cells in turn, returning the first whose closure separates a from
+// b and its index. Runs backwards, reopening the cells into a union-find
+pub fn first_disconnecting(grid: &Grid<bool>,
+                           closures: &[Offset],
+                           a: Offset,
+                           b: Offset) -> Option<(usize, Offset)> {
+
+    let mut open = grid.map(|&b| b);
+
+    for &offset in closures {
+
+        if let Some(cell) = open.get_mut(offset) { *cell = false; }
+    }
+
+    let mut first_closed = grid.map(|_| None);
+
+    for (index, &offset) in closures.iter().enumerate().rev() {
+
+        if let Some(cell) = first_closed.get_mut(offset) {
+
+            *cell = Some(index);
+        }
+    }
+
+    let mut ids = grid.map(|_| 0);
+
+    for (id, (offset, _)) in grid.iter().enumerate() {
+
+        *ids.get_mut(offset).unwrap() = id;
+    }
+
+    let id = |offset| *ids.get(offset).unwrap();
+
+    let mut sets = UnionFind::new(grid.iter().count());
+
+    let join = |sets: &mut UnionFind, open: &Grid<bool>, offset: Offset| {
+
+        for neighbour in Direction::ALL.map(|d| offset + d) {
+
+            if open.get(neighbour) == Some(&true) {
+
+                sets.union(id(offset), id(neighbour));
+            }
+        }
+    };
+
+    for (offset, _) in open.iter().filter(|&(_, &b)| b) {
+
+        join(&mut sets, &open, offset);
+    }
+
+    let connected = |sets: &mut UnionFind, open: &Grid<bool>|
+        open.get(a) == Some(&true) && open.get(b) == Some(&true)
+                                   && sets.connected(id(a), id(b));
+
+    if connected(&mut sets, &open) { return None; }
+
+    for (index, &offset) in closures.iter().enumerate().rev() {
+
+        if !grid.get(offset).is_some_and(|&b| b) { continue; }
+
+        if first_closed.get(offset) != Some(&Some(index)) { continue; }
+
+        *open.get_mut(offset).unwrap() = true;
+
+        join(&mut sets, &open, offset);
+
+        if connected(&mut sets, &open) { return Some((index, offset)); }
+    }
+
+    None // Never connected in the first place
+}
+
+#[test]
+fn test_first_disconnecting() {
+
+    let grid = Grid::<char>::parse_cells("...\n\
+                                          ...\n\
+                                          ...").unwrap()
+                            .map(|_| true);
+
+    let closures = [(1, 1), (0, 2), (2, 0), (1, 0), (0, 1), (2, 2)]
+                   .map(|(x, y)| Offset { x, y });
+
+    let (start, end) = (Offset { x: 0, y: 0 }, Offset { x: 2, y: 2 });
+
+    assert_eq!(first_disconnecting(&grid, &closures, start, end),
+               Some((2, Offset { x: 2, y: 0 })));
+
+    let connected_after = |n: usize| {
+
+        let mut open = grid.map(|&b| b);
+
+        for &offset in &closures[.. n] {
+
+            *open.get_mut(offset).unwrap() = false;
+        }
+
+        distance_field(&open, [start], |&b| b).get(end) != Some(&None)
+    };
+
+    assert_eq!(first_failing(closures.len(), connected_after), Some(2));
+
+    assert_eq!(first_failing(2, connected_after), None);
+
+    assert_eq!(first_failing(0, |_| false), None);
+
+    assert_eq!(first_failing(3, |_| false), None);
+
+    assert_eq!(first_failing(3, |n| n < 1), Some(0));
+
+    let repeated = [(1, 1), (2, 0), (1, 0), (2, 0), (0, 1)]
+                   .map(|(x, y)| Offset { x, y });
+
+    assert_eq!(first_disconnecting(&grid, &repeated, start, end),
+               Some((4, Offset { x: 0, y: 1 })));
+}
@@ -11,23 +11,14 @@ const EXAMPLE: &str = "89010123\n\
                        10456732";
 mod grid {
 
-    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-    pub struct Offset { pub x: isize, pub y: isize } // Can be used as a coord
+    use super::super::vector::Vec2;
+
+    pub type Offset = Vec2<isize>; // Can be used as a coord
 
     pub struct Grid<T> { cells: Vec<Vec<T>> } // Can be jagged
 
     pub struct GridIterator<'a, T> { grid: &'a Grid<T>, offset: Offset }
 
-    impl std::ops::Add<Offset> for Offset {
-
-        type Output = Self;
-
-        fn add(self, offset: Self) -> Self {
-
-            Self { x: self.x + offset.x, y: self.y + offset.y }
-        }
-    }
-   
     impl<T> Grid<T> {
         
         pub fn parse(text: &str, parse_char: impl Fn(char) -> Result<T, String>)
@@ -46,7 +37,7 @@ mod grid {
                 .and_then(|(x, y)| self.cells.get(y).and_then(|v| v.get(x)))
         }
         
-        pub fn iter(&self) -> GridIterator<T> {
+        pub fn iter(&self) -> GridIterator<'_, T> {
 
             GridIterator { grid: self, offset: Offset { x: -1, y: 0 } }
         }
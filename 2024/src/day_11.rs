@@ -1,7 +1,9 @@
 
 const INPUT: &str = "28591 78 0 3159881 4254 524155 598 1";
 
-type Cache = std::collections::BTreeMap<(usize, usize), usize>;
+use super::{ checked::*, memo::* };
+
+type Cache = Memo<(usize, usize), usize>;
 
 fn digit_count(num: usize) -> u32 {
 
@@ -21,11 +23,9 @@ fn count_stones(stone: usize, blinks: usize, cache: &mut Cache) -> usize {
 
     if blinks == 0 { return 1; }
 
-    if let Some(&count) = cache.get(&(stone, blinks)) { return count; }
-
-    let mut count_stones = |s| count_stones(s, blinks - 1, cache);
+    cache.get_or_insert_with((stone, blinks), |cache| {
 
-    let count = {
+        let mut count_stones = |s| count_stones(s, blinks - 1, cache);
 
         if stone == 0 { return count_stones(1); }
 
@@ -34,13 +34,9 @@ fn count_stones(stone: usize, blinks: usize, cache: &mut Cache) -> usize {
             d if d % 2 == 0 => split_digits(stone, d / 2).map(count_stones)
                                                          .iter()
                                                          .sum(),
-            _ => count_stones(stone * 2024)
+            _ => count_stones((Checked(stone) * Checked(2024)).get())
         }
-    };
-
-    cache.insert((stone, blinks), count);
-
-    count
+    })
 }
 
 fn get_result(input: &str, blinks: usize) -> usize {
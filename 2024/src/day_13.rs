@@ -17,8 +17,10 @@ const EXAMPLE: &str = "Button A: X+94, Y+34\n\
                        Button B: X+27, Y+71\n\
                        Prize: X=18641, Y=10279";
 
-#[derive(Clone, Copy)]
-struct Offset { x: isize, y: isize }
+use super::{ checked::*, linear::*, number::extended_gcd, rational::Rational,
+             vector::Vec2 };
+
+type Offset = Vec2<isize>;
 
 struct Button { offset: Offset, tokens: isize }
 
@@ -33,7 +35,7 @@ impl Machine {
             let values = line.split([':', ','])
                              .collect::<Vec<_>>()[1 ..]
                              .iter()
-                             .map(|f| f.split(['+', '=']).last().unwrap())
+                             .map(|f| f.split(['+', '=']).next_back().unwrap())
                              .map(|f| f.parse().unwrap())
                              .collect::<Vec<_>>();
     
@@ -48,42 +50,106 @@ impl Machine {
     }
 }
 
-fn button_line_intersection(m: &Machine) -> Offset {
+// Presses i and j, each at least zero, with a.i + b.j = target, at the least
+// cost. For when the buttons both move along the same line, either way
+fn min_tokens_on_line(a: i128, b: i128, target: i128, costs: [i128; 2])
+    -> Option<i128> {
+
+    let (g, x, y) = extended_gcd(a, b);
+
+    let presses = |step: i128| (target % step == 0 && target / step >= 0)
+                               .then_some(target / step);
+
+    match (a, b) {
+        (0, 0) => return (target == 0).then_some(0),
+        (0, b) => return presses(b).map(|j| costs[1] * j),
+        (a, 0) => return presses(a).map(|i| costs[0] * i),
+        _ if target % g != 0 => return None,
+        _ => {}
+    }
+
+    // Every solution is (i + k.i_step, j + k.j_step) for some k
+    let (i, j) = (x * target / g, y * target / g);
+    let (i_step, j_step) = (b / g, -a / g);
+
+    // Keeping count + k.step at least zero bounds k on one side
+    let (mut k_min, mut k_max) = (None::<i128>, None::<i128>);
+
+    for (count, step) in [(i, i_step), (j, j_step)] {
 
-    let numerator_factor = m.prize.x * m.buttons[1].offset.y
-                         - m.prize.y * m.buttons[1].offset.x;
+        if step > 0 {
+            k_min = k_min.max(Some(-count.div_euclid(step)));
+        } else {
+            let bound = count.div_euclid(-step);
+            k_max = Some(k_max.map_or(bound, |k| k.min(bound)));
+        }
+    }
+
+    if let (Some(low), Some(high)) = (k_min, k_max) {
+
+        if low > high { return None; }
+    }
 
-    let denominator = m.buttons[0].offset.x * m.buttons[1].offset.y
-                    - m.buttons[0].offset.y * m.buttons[1].offset.x;
+    let slope = costs[0] * i_step + costs[1] * j_step;
 
-    Offset { x: m.buttons[0].offset.x * numerator_factor / denominator,
-             y: m.buttons[0].offset.y * numerator_factor / denominator }
+    let k = if slope >= 0 { k_min } else { k_max }?; // Else no least cost
+
+    Some(costs[0] * (i + k * i_step) + costs[1] * (j + k * j_step))
+}
+
+#[test]
+fn on_line() {
+
+    assert_eq!(min_tokens_on_line(0, 2, -4, [3, 1]), None);
+
+    assert_eq!(min_tokens_on_line(2, 0, 4, [3, 1]), Some(6));
+
+    assert_eq!(min_tokens_on_line(-2, 0, 4, [3, 1]), None);
+
+    assert_eq!(min_tokens_on_line(2, -3, 1, [3, 1]), Some(7));
+
+    assert_eq!(min_tokens_on_line(-4, -1, -5, [3, 1]), Some(4));
+
+    assert_eq!(min_tokens_on_line(4, 1, -5, [3, 1]), None);
 }
 
 fn min_tokens_to_prize(m: &Machine) -> Option<isize> {
 
-    let intersection = button_line_intersection(m);
+    let [a, b] = &m.buttons;
 
-    if intersection.x % m.buttons[0].offset.x != 0
-    || intersection.y % m.buttons[0].offset.y != 0
-    || (m.prize.x - intersection.x) % m.buttons[1].offset.x != 0
-    || (m.prize.y - intersection.y) % m.buttons[1].offset.y != 0 {
+    let matrix = [[a.offset.x, b.offset.x], [a.offset.y, b.offset.y]]
+                 .map(|r| r.map(|v| Rational::from(v as i64)).to_vec())
+                 .to_vec();
 
-        return None;
-    }
+    let prize = [m.prize.x, m.prize.y].map(|v| Rational::from(v as i64));
+
+    let tokens = match solve(matrix, prize.to_vec()) {
+
+        Solution::Unique(presses) => {
 
-    let tokens = [
+            let presses = presses.iter()
+                                 .map(|p| p.to_integer().filter(|&p| p >= 0))
+                                 .collect::<Option<Vec<_>>>()?;
 
-        intersection.x
-        * m.buttons[0].tokens
-        / m.buttons[0].offset.x,
+            presses[0] * a.tokens as i128 + presses[1] * b.tokens as i128
+        },
 
-        (m.prize.x - intersection.x)
-        * m.buttons[1].tokens
-        / m.buttons[1].offset.x
-    ];
+        Solution::None => return None,
 
-    Some(tokens.iter().sum())
+        Solution::Infinite { .. } => {
+
+            let (a_x, b_x, p_x) = if (a.offset.x, b.offset.x) != (0, 0) {
+                (a.offset.x, b.offset.x, m.prize.x)
+            } else {
+                (a.offset.y, b.offset.y, m.prize.y)
+            };
+
+            min_tokens_on_line(a_x as i128, b_x as i128, p_x as i128,
+                               [a.tokens as i128, b.tokens as i128])?
+        }
+    };
+
+    isize::try_from(tokens).ok()
 }
 
 mod part_1 {
@@ -100,6 +166,26 @@ mod part_1 {
 
     #[test]
     fn example() { assert_eq!(get_result(EXAMPLE), 480); }
+
+    #[test]
+    fn dependent_buttons() {
+
+        assert_eq!(get_result("Button A: X+2, Y+4\n\
+                               Button B: X+3, Y+6\n\
+                               Prize: X=12, Y=24\n\
+                               \n\
+                               Button A: X+2, Y+4\n\
+                               Button B: X+3, Y+6\n\
+                               Prize: X=12, Y=25\n\
+                               \n\
+                               Button A: X+4, Y+4\n\
+                               Button B: X+1, Y+1\n\
+                               Prize: X=5, Y=5\n\
+                               \n\
+                               Button A: X+4, Y+2\n\
+                               Button B: X+6, Y+3\n\
+                               Prize: X=1, Y=2"), 4 + 4);
+    }
     
     #[test]
     fn real() { assert_eq!(get_result(INPUT), 29187); }
@@ -111,9 +197,10 @@ mod part_2 {
 
     fn get_result(input: &str) -> isize {
 
+        let rig = |v| (Checked(v) + Checked(10000000000000)).get();
+
         let rig = |m: &mut Machine|
-            m.prize = Offset { x: m.prize.x + 10000000000000,
-                               y: m.prize.y + 10000000000000 };
+            m.prize = Offset { x: rig(m.prize.x), y: rig(m.prize.y) };
 
         input.split("\n\n")
              .map(Machine::parse)
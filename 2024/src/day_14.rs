@@ -14,34 +14,11 @@ const EXAMPLE: &str = "p=0,4 v=3,-3\n\
                        p=2,4 v=2,-3\n\
                        p=9,5 v=-3,-3";
 
-struct Robot { offset: Offset, velocity: Offset }
-
-#[derive(Clone, Copy)]
-struct Offset { x: isize, y: isize }
-
-impl From<(isize, isize)> for Offset {
-
-    fn from((x, y): (isize, isize)) -> Self { Self { x, y } }
-}
-
-struct Zone { from: Offset, to: Offset } // Inclusive to exclusive
-
-impl From<((isize, isize), (isize, isize))> for Zone {
-
-    fn from((from, to): ((isize, isize), (isize, isize))) -> Self {
-        
-        Self { from: from.into(), to: to.into() }
-    }
-}
-
-impl Zone {
+use super::{ number::*, vector::* };
 
-    fn contains(&self, offset: Offset) -> bool {
+type Offset = Vec2<isize>;
 
-           offset.x >= self.from.x && offset.y >= self.from.y
-        && offset.x < self.to.x && offset.y < self.to.y
-    }
-}
+struct Robot { offset: Offset, velocity: Offset }
 
 impl Robot {
 
@@ -59,17 +36,13 @@ impl Robot {
 
 fn safety_factor(robots: &[Robot], room: Offset) -> usize {
 
-    let quadrants: [Zone; 4] = [
-        ((0, 0), (room.x / 2, room.y / 2)).into(),
-        ((room.x / 2 + 1, 0), (room.x, room.y / 2)).into(),
-        ((0, room.y / 2 + 1), (room.x / 2, room.y)).into(),
-        ((room.x / 2 + 1, room.y / 2 + 1), (room.x, room.y)).into()
-    ];
-
-    let count_robots = |z: &Zone|
+    let count_robots = |z: &Rect<isize>|
         robots.iter().filter(|r| z.contains(r.offset)).count();
 
-    quadrants.iter().map(count_robots).product()
+    Rect::new(Offset::default(), room).quadrants()
+                                      .iter()
+                                      .map(count_robots)
+                                      .product()
 }
 
 mod part_1 {
@@ -81,15 +54,11 @@ mod part_1 {
         let mut robots =
             input.split('\n').map(Robot::parse).collect::<Vec<_>>();
 
-        let modulo = |lhs, rhs|
-            match lhs % rhs { v @ .. 0 => v + rhs, v => v };
-
         for robot in robots.iter_mut() {
 
-            robot.offset = Offset {
-                x: modulo(robot.offset.x + robot.velocity.x * 100, room.x),
-                y: modulo(robot.offset.y + robot.velocity.y * 100, room.y)
-            }
+            robot.offset += robot.velocity * 100;
+
+            robot.offset = robot.offset.rem_euclid(room);
         }
 
         safety_factor(&robots, room)
@@ -100,4 +69,45 @@ mod part_1 {
     
     #[test]
     fn real() { assert_eq!(get_result(INPUT, (101, 103).into()), 211773366); }
-}
\ No newline at end of file
+}
+
+mod part_2 {
+
+    use super::*;
+
+    // The robots bunch up into the tree when both axes are at their least
+    // spread, and each axis repeats on its own period
+    fn tightest_step(robots: &[Robot], axis: fn(Offset) -> isize, size: isize)
+        -> isize {
+
+        let spread = |step: isize| {
+
+            let positions = robots.iter()
+                                  .map(|r| axis(r.offset)
+                                           + axis(r.velocity) * step)
+                                  .map(|p| p.rem_euclid(size))
+                                  .collect::<Vec<_>>();
+
+            let mean = positions.iter().sum::<isize>() / robots.len() as isize;
+
+            positions.iter().map(|p| (p - mean).pow(2)).sum::<isize>()
+        };
+
+        (0 .. size).min_by_key(|&step| spread(step)).unwrap()
+    }
+
+    fn get_result(input: &str, room: Offset) -> i64 {
+
+        let robots = input.split('\n').map(Robot::parse).collect::<Vec<_>>();
+
+        let x_step = tightest_step(&robots, |o| o.x, room.x);
+        let y_step = tightest_step(&robots, |o| o.y, room.y);
+
+        crt([(x_step as i64, room.x as i64), (y_step as i64, room.y as i64)])
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn real() { assert_eq!(get_result(INPUT, (101, 103).into()), 7344); }
+}
@@ -7,90 +7,7 @@ const EXAMPLE_B: &str = include_str!("../examples/day_15_b.txt");
 
 const EXAMPLE_C: &str = include_str!("../examples/day_15_c.txt");
 
-mod grid {
-
-    #[derive(Clone, Copy, PartialEq, Eq)]
-    pub struct Offset { pub x: isize, pub y: isize } // Can be used as a coord
-
-    pub struct Grid<T> { pub cells: Vec<Vec<T>> } // Can be jagged
-
-    pub struct GridIterator<'a, T> { grid: &'a Grid<T>, offset: Offset }
-
-    impl std::ops::Add<Offset> for Offset {
-
-        type Output = Self;
-
-        fn add(self, offset: Self) -> Self {
-
-            Self { x: self.x + offset.x, y: self.y + offset.y }
-        }
-    }
-
-    impl std::ops::Mul<isize> for Offset {
-
-        type Output = Self;
-
-        fn mul(self, scalar: isize) -> Self {
-
-            Self { x: self.x * scalar, y: self.y * scalar}
-        }
-    }
-   
-    impl<T> Grid<T> {
-        
-        pub fn parse(text: &str, parse_char: impl Fn(char) -> Result<T, String>)
-            -> Result<Self, String> {
-
-            Ok(Self { cells: text.split('\n')
-                                 .map(|l| l.chars().map(&parse_char).collect())
-                                 .collect::<Result<_, _>>()? })
-        }
-
-        pub fn get(&self, offset: Offset) -> Option<&T> {
-
-            usize::try_from(offset.x)
-                .ok()
-                .zip(usize::try_from(offset.y).ok())
-                .and_then(|(x, y)| self.cells.get(y).and_then(|v| v.get(x)))
-        }
-
-        pub fn get_mut(&mut self, offset: Offset) -> Option<&mut T> {
-
-            usize::try_from(offset.x)
-                .ok()
-                .zip(usize::try_from(offset.y).ok())
-                .and_then(|(x, y)| self.cells.get_mut(y)
-                                             .and_then(|v| v.get_mut(x)))
-        }
-        
-        pub fn iter(&self) -> GridIterator<T> {
-
-            GridIterator { grid: self, offset: Offset { x: -1, y: 0 } }
-        }
-    }
-
-    impl<'a, T> Iterator for GridIterator<'a, T> {
-        
-        type Item = (Offset, &'a T);
-
-        fn next(&mut self) -> Option<Self::Item> {
-
-            if self.offset.y as usize >= self.grid.cells.len() { return None; }
-
-            self.offset.x += 1;
-
-            match self.grid.get(self.offset) {
-
-                Some(v) => Some((self.offset, v)),
-
-                _ => { self.offset = Offset { x: -1, y: self.offset.y + 1 };
-                       self.next() }
-            }
-        }
-    }
-}
-
-use grid::*;
+use super::grid::*;
 
 struct Warehouse { grid: Grid<char>, robot: Offset }
 
@@ -117,7 +34,7 @@ impl Warehouse {
 
                 if c == '.' || c == '#' { return Some((offset, c)); }
 
-                offset = offset + direction;
+                offset += direction;
             }
 
             None
@@ -125,7 +42,7 @@ impl Warehouse {
 
         if let Some((offset, '.')) = find_non_box() {
 
-            self.robot = self.robot + direction;
+            self.robot += direction;
 
             if self.robot != offset {
 
@@ -135,21 +52,23 @@ impl Warehouse {
             }
         }
     }
+
+    fn render(&self) -> String {
+
+        self.grid.render(|o, &c| if o == self.robot { '@' } else { c })
+    }
 }
 
 fn parse_direction(char: char) -> Offset {
 
-    match char { '^' => Offset { x:  0, y: -1 },
-                 'v' => Offset { x:  0, y:  1 },
-                 '<' => Offset { x: -1, y:  0 },
-                 _   => Offset { x:  1, y:  0 } }
+    Direction::parse(char, Direction::ARROWS).unwrap().into()
 }
 
 mod part_1 {
 
     use super::*;
 
-    fn get_result(input: &str) -> isize {
+    fn moved(input: &str) -> Warehouse {
 
         let mut parts = input.split("\n\n");
 
@@ -163,15 +82,44 @@ mod part_1 {
             warehouse.move_robot(direction);
         }
 
-        warehouse.grid.iter()
-                      .filter(|(_, &c)| c == 'O')
-                      .map(|(o, _)| o.y * 100 + o.x)
-                      .sum()
+        warehouse
+    }
+
+    fn get_result(input: &str) -> isize {
+
+        moved(input).grid
+                    .iter()
+                    .filter(|(_, &c)| c == 'O')
+                    .map(|(o, _)| o.y * 100 + o.x)
+                    .sum()
     }
 
     #[test]
     fn example_a() { assert_eq!(get_result(EXAMPLE_A), 2028); }
 
+    #[test]
+    fn example_a_render() {
+
+        let warehouse = moved(EXAMPLE_A);
+
+        assert_eq!(warehouse.render(), "########\n\
+                                        #....OO#\n\
+                                        ##.....#\n\
+                                        #.....O#\n\
+                                        #.#O@..#\n\
+                                        #...O..#\n\
+                                        #...O..#\n\
+                                        ########");
+    }
+
+    #[test]
+    fn example_a_round_trip() {
+
+        let map = EXAMPLE_A.split("\n\n").next().unwrap();
+
+        assert_eq!(Warehouse::parse(map).render(), map);
+    }
+
     #[test]
     fn example_b() { assert_eq!(get_result(EXAMPLE_B), 10092); }
     
@@ -189,12 +137,12 @@ mod part_2 {
                                         'O' => ['[', ']'],
                                         '@' => ['@', '.'],
                                          c  => [ c,   c ] };
-        let mut grid = Grid { // TODO: Make parse which take iter of char?
+        let mut grid = Grid::from(
 
-            cells: input.split('\n')
-                        .map(|l| l.chars().flat_map(expand_char).collect())
-                        .collect()
-        };
+            input.split('\n')
+                 .map(|l| l.chars().flat_map(expand_char).collect())
+                 .collect::<Vec<_>>()
+        );
 
         let robot = grid.iter().find(|(_, &char)| char == '@').unwrap().0;
 
@@ -203,13 +151,10 @@ mod part_2 {
         Warehouse { robot, grid }
     }
 
-    const LEFT:  Offset = Offset { x: -1, y: 0 };
-    const RIGHT: Offset = Offset { x:  1, y: 0 };
-
-    fn other_edge(char: Option<&char>) -> Option<Offset> {
+    fn other_edge(char: Option<&char>) -> Option<Direction> {
 
-        match char { Some('[') => Some(RIGHT),
-                     Some(']') => Some(LEFT),
+        match char { Some('[') => Some(Direction::Right),
+                     Some(']') => Some(Direction::Left),
                      _         => None }
     }
 
@@ -257,11 +202,13 @@ mod part_2 {
 
             (_, Some('[')) =>
                 can_move(grid, offset + direction, direction)
-                && can_move(grid, offset + direction + RIGHT, direction),
+                && can_move(grid, offset + direction + Direction::Right,
+                            direction),
 
             (_, Some(']')) =>
                 can_move(grid, offset + direction, direction)
-                && can_move(grid, offset + direction + LEFT, direction),
+                && can_move(grid, offset + direction + Direction::Left,
+                            direction),
 
             _ => false
         }
@@ -271,13 +218,13 @@ mod part_2 {
 
         if can_move(&warehouse.grid, warehouse.robot + direction, direction) {
             
-            warehouse.robot = warehouse.robot + direction;
+            warehouse.robot += direction;
 
             move_boxes(&mut warehouse.grid, warehouse.robot, direction);
         }
     }
 
-    fn get_result(input: &str) -> isize {
+    fn moved(input: &str) -> Warehouse {
 
         let mut parts = input.split("\n\n");
 
@@ -291,15 +238,35 @@ mod part_2 {
             move_robot(&mut warehouse, direction);
         }
 
-        warehouse.grid.iter()
-                      .filter(|(_, &c)| c == '[')
-                      .map(|(o, _)| o.y * 100 + o.x)
-                      .sum()
+        warehouse
+    }
+
+    fn get_result(input: &str) -> isize {
+
+        moved(input).grid
+                    .iter()
+                    .filter(|(_, &c)| c == '[')
+                    .map(|(o, _)| o.y * 100 + o.x)
+                    .sum()
     }
 
     #[test]
     fn example_c() { assert_eq!(get_result(EXAMPLE_C), 618); }
 
+    #[test]
+    fn example_c_render() {
+
+        let warehouse = moved(EXAMPLE_C);
+
+        assert_eq!(warehouse.render(), "##############\n\
+                                        ##...[].##..##\n\
+                                        ##...@.[]...##\n\
+                                        ##....[]....##\n\
+                                        ##..........##\n\
+                                        ##..........##\n\
+                                        ##############");
+    }
+
     #[test]
     fn example_b() { assert_eq!(get_result(EXAMPLE_B), 9021); }
 
@@ -35,109 +35,7 @@ const EXAMPLE_B: &str = "#################\n\
                          #S#.............#\n\
                          #################";
 
-mod grid {
-
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-    pub struct Offset { pub x: isize, pub y: isize } // Can be used as a coord
-
-    pub struct Grid<T> { cells: Vec<Vec<T>> } // Can be jagged
-
-    pub struct GridIterator<'a, T> { grid: &'a Grid<T>, offset: Offset }
-
-    impl std::ops::Add<Offset> for Offset {
-
-        type Output = Self;
-
-        fn add(self, offset: Self) -> Self {
-
-            Self { x: self.x + offset.x, y: self.y + offset.y }
-        }
-    }
-
-    impl std::ops::Sub<Offset> for Offset {
-
-        type Output = Self;
-
-        fn sub(self, offset: Self) -> Self {
-
-            Self { x: self.x - offset.x, y: self.y - offset.y }
-        }
-    }
-   
-    impl<T> Grid<T> {
-        
-        pub fn parse(text: &str, parse_char: impl Fn(char) -> Result<T, String>)
-            -> Result<Self, String> {
-
-            Ok(Self { cells: text.split('\n')
-                                 .map(|l| l.chars().map(&parse_char).collect())
-                                 .collect::<Result<_, _>>()? })
-        }
-
-        pub fn get(&self, offset: Offset) -> Option<&T> {
-
-            usize::try_from(offset.x)
-                .ok()
-                .zip(usize::try_from(offset.y).ok())
-                .and_then(|(x, y)| self.cells.get(y).and_then(|v| v.get(x)))
-        }
-
-        pub fn get_mut(&mut self, offset: Offset) -> Option<&mut T> {
-
-            usize::try_from(offset.x)
-                .ok()
-                .zip(usize::try_from(offset.y).ok())
-                .and_then(|(x, y)| self.cells.get_mut(y)
-                                             .and_then(|v| v.get_mut(x)))
-        }
-
-        pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
-
-            Grid::<U> { cells: self.cells.iter()
-                                         .map(|v| v.iter().map(&f).collect())
-                                         .collect() }
-        }
-
-        pub fn iter(&self) -> GridIterator<T> {
-
-            GridIterator { grid: self, offset: Offset { x: -1, y: 0 } }
-        }
-    }
-
-    impl<'a, T> Iterator for GridIterator<'a, T> {
-        
-        type Item = (Offset, &'a T);
-
-        fn next(&mut self) -> Option<Self::Item> {
-
-            if self.offset.y as usize >= self.grid.cells.len() { return None; }
-
-            self.offset.x += 1;
-
-            match self.grid.get(self.offset) {
-
-                Some(v) => Some((self.offset, v)),
-
-                _ => { self.offset = Offset { x: -1, y: self.offset.y + 1 };
-                       self.next() }
-            }
-        }
-    }
-}
-
-use grid::*;
-
-const DIRECTIONS: [Offset; 4] = [Offset { x:  0, y: -1 },
-                                 Offset { x:  1, y:  0 },
-                                 Offset { x:  0, y:  1 },
-                                 Offset { x: -1, y:  0 }];
-
-fn diection_index(direction: Offset) -> usize {
-
-    DIRECTIONS.iter().position(|&o| o == direction).unwrap()
-}
-
-fn turn(d: Offset, m: isize) -> Offset { Offset { x: -d.y * m, y: d.x * m } }
+use super::{ grid::*, search::* };
 
 struct Maze { grid: Grid<char>, start: Offset, end: Offset }
 
@@ -158,34 +56,24 @@ impl Maze {
         Self { grid, start, end }
     }
 
-    fn scores(&self) -> Grid<[usize; 4]> {
-
-        let mut scores = self.grid.map(|_| [usize::MAX; 4]);
-
-        let mut todo = vec![(self.start, Offset { x: 1, y: 0 }, 0)];
-
-        while let Some((offset, d, score)) = todo.pop() {
-
-            if self.grid.get(offset) != Some(&'.') { continue; }
-
-            let direction_score = scores.get_mut(offset)
-                                        .unwrap()
-                                        .get_mut(diection_index(d))
-                                        .unwrap();
-
-            if score < *direction_score {
-
-                *direction_score = score;
+    fn successors(&self, &(offset, d): &(Offset, Direction))
+        -> impl Iterator<Item = ((Offset, Direction), usize)> + '_ {
 
-                todo.push((offset + d, d, score + 1));
-
-                todo.push((offset, turn(d, 1), score + 1000));
+        [((offset + d, d), 1),
+         ((offset, d.turned(Turn::Right)), 1000),
+         ((offset, d.turned(Turn::Left)), 1000)]
+            .into_iter()
+            .filter(|&((o, _), _)| self.grid.get(o) == Some(&'.'))
+    }
 
-                todo.push((offset, turn(d, -1), score + 1000));
-            }
-        }
+    fn min_score(&self) -> usize {
 
-        scores
+        astar([(self.start, Direction::Right)],
+              |s| self.successors(s),
+              manhattan(self.end, |&(o, _)| o),
+              |&(o, _)| o == self.end)
+            .goal_cost()
+            .unwrap()
     }
 }
 
@@ -193,12 +81,7 @@ mod part_1 {
 
     use super::*;
 
-    fn get_result(input: &str) -> usize {
-
-        let maze = Maze::parse(input);
-
-        *maze.scores().get(maze.end).unwrap().iter().min().unwrap()
-    }
+    fn get_result(input: &str) -> usize { Maze::parse(input).min_score() }
    
     #[test]
     fn example_a() { assert_eq!(get_result(EXAMPLE_A), 7036); }
@@ -214,63 +97,57 @@ mod part_2 {
 
     use super::*;
 
-    fn get_result(input: &str) -> usize {
-
-        let maze = Maze::parse(input);
-
-        let scores = maze.scores();
+    fn best_tiles(maze: &Maze) -> Grid<bool> {
 
-        let direction_score = |o, d| scores.get(o).unwrap()[diection_index(d)];
+        let dag = shortest_path_dag([(maze.start, Direction::Right)],
+                                    |s| maze.successors(s),
+                                    |&(o, _)| o == maze.end);
 
-        let mut visited = maze.grid.map(|_| false);
+        let mut best_tiles = maze.grid.map(|_| false);
 
-        *visited.get_mut(maze.end).unwrap() = true;
+        for (offset, _) in dag.nodes() {
 
-        let &min_score =
-            maze.scores().get(maze.end).unwrap().iter().min().unwrap();
-
-        let mut todo =
-            Vec::from(DIRECTIONS.map(|d| (maze.end - d, d, min_score - 1)));
-
-        while let Some((offset, direction, target_score)) = todo.pop() {
-
-            if direction_score(offset, direction) != target_score { continue; }
-
-            let visited_cell = visited.get_mut(offset).unwrap();
-
-            if *visited_cell { continue; }
-
-            *visited_cell = true;
-
-            if target_score == 0 { continue; }
-
-            let mut push = |direction, delta|
-                todo.push((offset - direction, direction, target_score - delta));
-
-            push(direction, 1);
-
-            if target_score < 1001 { continue; }
-
-            let mut turn_n_push = |m| {
-
-                let direction = turn(direction, m);
-
-                if direction_score(offset, direction) + 1000 == target_score {
+            *best_tiles.get_mut(offset).unwrap() = true;
+        }
 
-                    push(direction, 1001);
-                }
-            };
+        best_tiles
+    }
 
-            turn_n_push(1);
-            turn_n_push(-1);
-        }
+    fn get_result(input: &str) -> usize {
 
-        visited.iter().filter(|&(_, &b)| b).count()
+        best_tiles(&Maze::parse(input)).iter().filter(|&(_, &b)| b).count()
     }
   
     #[test]
     fn example_a() { assert_eq!(get_result(EXAMPLE_A), 45); }
 
+    #[test]
+    fn example_a_render() {
+
+        let maze = Maze::parse(EXAMPLE_A);
+
+        let best_tiles = best_tiles(&maze);
+
+        let render = maze.grid.render(|o, &c|
+            if *best_tiles.get(o).unwrap() { 'O' } else { c });
+
+        assert_eq!(render, "###############\n\
+                            #.......#....O#\n\
+                            #.#.###.#.###O#\n\
+                            #.....#.#...#O#\n\
+                            #.###.#####.#O#\n\
+                            #.#.#.......#O#\n\
+                            #.#.#####.###O#\n\
+                            #..OOOOOOOOO#O#\n\
+                            ###O#O#####O#O#\n\
+                            #OOO#O....#O#O#\n\
+                            #O#O#O###.#O#O#\n\
+                            #OOOOO#...#O#O#\n\
+                            #O###.#.#.#O#O#\n\
+                            #O..#.....#OOO#\n\
+                            ###############");
+    }
+
     #[test]
     fn example_b() { assert_eq!(get_result(EXAMPLE_B), 64); }
     
@@ -21,7 +21,7 @@ impl Computer {
         let registers: [_; 3] = sections.next()
                                         .unwrap()
                                         .split('\n')
-                                        .map(|l| l.split(' ').last().unwrap())
+                                        .map(|l| l.split(' ').next_back().unwrap())
                                         .map(|f| f.parse().unwrap())
                                         .collect::<Vec<_>>()
                                         .try_into()
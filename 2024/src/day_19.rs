@@ -3,28 +3,25 @@ const INPUT: &str = include_str!("../input/day_19.txt");
 
 const EXAMPLE: &str = include_str!("../examples/day_19.txt");
 
-use std::collections::BTreeMap;
+use super::memo::*;
 
 fn count_arrangements<'a>(design: &'a [char],
                           towels: &[Vec<char>],
-                          cache: &mut BTreeMap<&'a [char], usize>) -> usize {
+                          cache: &mut Memo<&'a [char], usize>) -> usize {
 
     if design.is_empty() { return 1; }
 
-    if let Some(&result) = cache.get(design) { return result; }
+    cache.get_or_insert_with(design, |cache| {
 
-    let counts = towels.iter().map(|t| {
+        let counts = towels.iter().map(|t| {
 
-        if !design.starts_with(t) { return 0; }
+            if !design.starts_with(t) { return 0; }
 
-        count_arrangements(&design[t.len() ..], towels, cache)
-    });
+            count_arrangements(&design[t.len() ..], towels, cache)
+        });
 
-    let count = counts.sum();
-
-    cache.insert(design, count);
-
-    count
+        counts.sum()
+    })
 }
 
 fn get_result(input: &str, map_fn: impl Fn(usize) -> usize) -> usize {
@@ -40,7 +37,7 @@ fn get_result(input: &str, map_fn: impl Fn(usize) -> usize) -> usize {
             .unwrap()
             .split('\n')
             .map(|l| l.chars().collect::<Vec<_>>())
-            .map(|l| count_arrangements(&l, &towels, &mut BTreeMap::new()))
+            .map(|l| count_arrangements(&l, &towels, &mut Memo::new()))
             .map(map_fn)
             .sum()
 }
@@ -11,84 +11,8 @@ const EXAMPLE: &str = "MMMSXXMASM\n\
                        SAXAMASAAA\n\
                        MAMMMXMMMM\n\
                        MXMXAXMASX";
-mod grid {
 
-    #[derive(Clone, Copy, PartialEq, Eq)]
-    pub struct Offset { pub x: isize, pub y: isize } // Can be used as a coord
-
-    pub struct Grid<T> { cells: Vec<Vec<T>> } // Can be jagged
-
-    pub struct GridIterator<'a, T> { grid: &'a Grid<T>, offset: Offset }
-
-    impl std::ops::Add<Offset> for Offset {
-
-        type Output = Self;
-
-        fn add(self, offset: Self) -> Self {
-
-            Self { x: self.x + offset.x, y: self.y + offset.y }
-        }
-    }
-
-    impl std::ops::Mul<isize> for Offset {
-
-        type Output = Self;
-
-        fn mul(self, scalar: isize) -> Self {
-
-            Self { x: self.x * scalar, y: self.y * scalar}
-        }
-    }
-   
-    impl<T> Grid<T> {
-        
-        pub fn parse(text: &str, parse_char: impl Fn(char) -> Result<T, String>)
-            -> Result<Self, String> {
-
-            Ok(Self {
-                
-                cells: text.split('\n')
-                           .map(|l| l.chars().map(&parse_char).collect())
-                           .collect::<Result<_, _>>()?
-            })
-        }
-
-        pub fn get(&self, offset: Offset) -> Option<&T> {
-
-            usize::try_from(offset.x)
-                .ok()
-                .zip(usize::try_from(offset.y).ok())
-                .and_then(|(x, y)| self.cells.get(y).and_then(|v| v.get(x)))
-        }
-        
-        pub fn iter(&self) -> GridIterator<T> {
-
-            GridIterator { grid: self, offset: Offset { x: -1, y: 0 } }
-        }
-    }
-
-    impl<'a, T> Iterator for GridIterator<'a, T> {
-        
-        type Item = (Offset, &'a T);
-
-        fn next(&mut self) -> Option<Self::Item> {
-
-            if self.offset.y as usize >= self.grid.cells.len() { return None; }
-
-            self.offset.x += 1;
-
-            match self.grid.get(self.offset) {
-
-                Some(v) => Some((self.offset, v)),
-
-                _ => { self.offset = Offset { x: -1, y: self.offset.y + 1 };
-                       self.next() }
-            }
-        }
-    }
-}
-
-use grid::*;
+use super::grid::*;
 
 fn is_match(grid: &Grid<char>, target: &[char], offset: Offset, direction: Offset)
     -> bool { 
@@ -100,17 +24,13 @@ fn is_match(grid: &Grid<char>, target: &[char], offset: Offset, direction: Offse
 
 mod part_1 {
 
-    use super::{ *, grid::* };
+    use super::*;
 
     fn get_result(input: &str) -> usize {
 
         let grid = Grid::parse(input, Ok).unwrap();
 
-        let directions =
-            [-1, 0, 1].iter()
-                      .flat_map(|&x| [-1, 0, 1].map(|y| Offset { x, y }))
-                      .filter(|&o| o != Offset { x: 0, y: 0 })
-                      .collect::<Vec<_>>();
+        let directions = Direction8::ALL.map(Offset::from);
 
         let count_matches = |target, offset|
             directions.iter()
@@ -131,7 +51,7 @@ mod part_1 {
 
 mod part_2 {
 
-    use super::{ *, grid::* };
+    use super::*;
 
     fn get_result(input: &str) -> usize {
 
@@ -3,7 +3,9 @@ const INPUT: &str = include_str!("../input/day_5.txt");
 
 const EXAMPLE: &str = include_str!("../examples/day_5.txt");
 
-fn parse(input: &str) -> (Vec<(usize, usize)>, Vec<Vec<usize>>) {
+use super::graph::*;
+
+fn parse(input: &str) -> (Graph<usize>, Vec<Vec<usize>>) { // Updates as ids
 
     let sections = input.split("\n\n").collect::<Vec<_>>();
 
@@ -12,24 +14,17 @@ fn parse(input: &str) -> (Vec<(usize, usize)>, Vec<Vec<usize>>) {
             .map(|f| f.parse::<usize>().unwrap())
             .collect::<Vec<_>>();
 
-    let rules = sections[0].split('\n')
-                           .map(|l| parse_usizes('|', l))
-                           .map(|v| (v[0], v[1]))
-                           .collect::<Vec<_>>();
+    let mut rules = Graph::from_edges(sections[0].split('\n')
+                                                 .map(|l| parse_usizes('|', l))
+                                                 .map(|v| (v[0], v[1])));
 
     let updates = sections[1].split('\n')
-                             .map(|l| parse_usizes(',', l))
+                             .map(|l| parse_usizes(',', l).into_iter()
+                                                          .map(|p| rules.id(p))
+                                                          .collect())
                              .collect::<Vec<_>>();
-    (rules, updates)
-}
 
-fn meets_rule(rule: (usize, usize), update: &[usize]) -> bool {
-
-    update.iter()
-          .copied()
-          .find(|&p| p == rule.0 || p == rule.1)
-          .filter(|&p| p == rule.1 && update.contains(&rule.0))
-          .is_none()
+    (rules, updates)
 }
 
 mod part_1 {
@@ -41,11 +36,11 @@ mod part_1 {
         let (rules, updates) = parse(input);
 
         updates.iter()
-               .filter(|u| rules.iter().all(|&r| meets_rule(r, u)))
-               .map(|u| u[u.len() / 2])
+               .filter(|u| rules.respects_order(u))
+               .map(|u| rules.name(u[u.len() / 2]))
                .sum()
     }
-  
+   
     #[test]
     fn example() { assert_eq!(get_result(EXAMPLE), 143); }
     
@@ -55,58 +50,22 @@ mod part_1 {
 
 mod part_2 {
 
-    use std::collections::{ BTreeMap, BTreeSet };
-
     use super::*;
 
-    fn reordered(pages: &[usize], rules: &[(usize, usize)]) -> Vec<usize> {
-
-        let mut dependencies: BTreeMap<usize, BTreeSet<usize>> =
-            BTreeMap::from_iter(pages.iter().map(|&p| (p, BTreeSet::new())));
-
-        for (page_a, page_b) in rules {
-
-            if dependencies.contains_key(page_a) {
-
-                if let Some(set) = dependencies.get_mut(page_b) {
-
-                    set.insert(*page_a);
-                }
-            }
-        }
-
-        let mut reordered = Vec::new();
-
-        while !dependencies.is_empty() {
-            
-            let page = *dependencies.iter()
-                                    .find(|(_, s)| s.is_empty())
-                                    .unwrap()
-                                    .0;
-            reordered.push(page);
-
-            dependencies.remove(&page);
-
-            for (_, set) in dependencies.iter_mut() { set.remove(&page); }
-        }
-
-        reordered
-    }
-
     fn get_result(input: &str) -> usize {
 
         let (rules, updates) = parse(input);
 
         updates.iter()
-               .filter(|u| !rules.iter().all(|&r| meets_rule(r, u)))
-               .map(|u| reordered(u, &rules))
-               .map(|u| u[u.len() / 2])
+               .filter(|u| !rules.respects_order(u))
+               .map(|u| rules.sort_subset(u).unwrap())
+               .map(|u| rules.name(u[u.len() / 2]))
                .sum()
     }
-
+   
     #[test]
     fn example() { assert_eq!(get_result(EXAMPLE), 123); }
     
     #[test]
     fn real() { assert_eq!(get_result(INPUT), 5502); }
-}
\ No newline at end of file
+}
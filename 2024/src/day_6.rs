@@ -11,90 +11,11 @@ const EXAMPLE: &str = "....#.....\n\
                        ........#.\n\
                        #.........\n\
                        ......#...";
-mod grid {
 
-    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-    pub struct Offset { pub x: isize, pub y: isize } // Can be used as a coord
-
-    pub struct Grid<T> { cells: Vec<Vec<T>> } // Can be jagged
-
-    pub struct GridIterator<'a, T> { grid: &'a Grid<T>, offset: Offset }
-
-    impl std::ops::Add<Offset> for Offset {
-
-        type Output = Self;
-
-        fn add(self, offset: Self) -> Self {
-
-            Self { x: self.x + offset.x, y: self.y + offset.y }
-        }
-    }
-   
-    impl<T> Grid<T> {
-        
-        pub fn parse(text: &str, parse_char: impl Fn(char) -> Result<T, String>)
-            -> Result<Self, String> {
-
-            Ok(Self { cells: text.split('\n')
-                                 .map(|l| l.chars().map(&parse_char).collect())
-                                 .collect::<Result<_, _>>()? })
-        }
-
-        pub fn get(&self, offset: Offset) -> Option<&T> {
-
-            usize::try_from(offset.x)
-                .ok()
-                .zip(usize::try_from(offset.y).ok())
-                .and_then(|(x, y)| self.cells.get(y).and_then(|v| v.get(x)))
-        }
-
-        pub fn get_mut(&mut self, offset: Offset) -> Option<&mut T> {
-
-            usize::try_from(offset.x)
-                .ok()
-                .zip(usize::try_from(offset.y).ok())
-                .and_then(|(x, y)| self.cells.get_mut(y)
-                                             .and_then(|v| v.get_mut(x)))
-        }
-        
-        pub fn iter(&self) -> GridIterator<T> {
-
-            GridIterator { grid: self, offset: Offset { x: -1, y: 0 } }
-        }
-
-        pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
-
-            Grid::<U> { cells: self.cells.iter()
-                                         .map(|v| v.iter().map(&f).collect())
-                                         .collect() }
-        }
-    }
-
-    impl<'a, T> Iterator for GridIterator<'a, T> {
-        
-        type Item = (Offset, &'a T);
-
-        fn next(&mut self) -> Option<Self::Item> {
-
-            if self.offset.y as usize >= self.grid.cells.len() { return None; }
-
-            self.offset.x += 1;
-
-            match self.grid.get(self.offset) {
-
-                Some(v) => Some((self.offset, v)),
-
-                _ => { self.offset = Offset { x: -1, y: self.offset.y + 1 };
-                       self.next() }
-            }
-        }
-    }
-}
-
-use grid::*;
+use super::grid::*;
 
 #[derive(Clone, Copy)]
-struct Position { offset: Offset, direction: Offset }
+struct Position { offset: Offset, direction: Direction }
 
 impl Position {
 
@@ -112,15 +33,11 @@ fn get_route(grid: &Grid<char>, mut position: Position) -> Route {
 
     let mut visted_mask = grid.map(|_| [false; 4]); // Flag per direction
 
-    let to_index = |direction: Offset| // Returns 0..=3 for sane directions
-        match direction.x { 0 => direction.y + 1, x => x + 2 } as usize;
-
     while let Some(&char) = grid.get(position.next().offset) {
 
-        if char == '#' { // Turn right (clockwise)
+        if char == '#' {
 
-            let direction = Offset { x: -position.direction.y,
-                                     y:  position.direction.x };
+            let direction = position.direction.turned(Turn::Right);
 
             position = Position { direction, ..position };
         }
@@ -130,7 +47,7 @@ fn get_route(grid: &Grid<char>, mut position: Position) -> Route {
 
             let visited = visted_mask.get_mut(position.offset)
                                      .unwrap()
-                                     .get_mut(to_index(position.direction))
+                                     .get_mut(position.direction.to_index())
                                      .unwrap();
 
             if *visited { return Route { positions, loops: true }; }
@@ -148,7 +65,7 @@ fn get_start_at(grid: &Grid<char>) -> Position {
 
     Position {
         offset: grid.iter().find(|(_, &c)| c == '^').unwrap().0,
-        direction: Offset { x: 0, y: -1 } // Up
+        direction: Direction::Up
     }
 }
 
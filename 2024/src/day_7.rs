@@ -11,31 +11,35 @@ const EXAMPLE: &str = "190: 10 19\n\
                        21037: 9 7 18 13\n\
                        292: 11 6 16 20";
 
-fn get_result(input: &str,
-              operators: &[impl Fn(usize, usize) -> usize]) -> usize {
+use super::checked::*;
+
+type Operator = fn(Checked<usize>, Checked<usize>) -> Option<Checked<usize>>;
+
+fn get_result(input: &str, operators: &[Operator]) -> usize {
 
     input.split('\n')
          .map(|l| l.split(' ')
                    .map(|f| f.trim_end_matches(':').parse::<usize>().unwrap())
                    .collect::<Vec<_>>())
-         .filter(|v| is_possible(v[0], v[1], &v[2 ..], operators))
+         .filter(|v| is_possible(Checked(v[0]),
+                                 Checked(v[1]),
+                                 &v[2 ..],
+                                 operators))
          .map(|v| v[0])
          .sum()
 }
 
-fn is_possible(target: usize,
-               total: usize,
+fn is_possible(target: Checked<usize>,
+               total: Checked<usize>,
                series: &[usize],
-               operators: &[impl Fn(usize, usize) -> usize]) -> bool {
+               operators: &[Operator]) -> bool { // Overflowing can't hit it
 
     if total > target { return false; }
 
     if series.is_empty() { return target == total; }
 
-    operators.iter().any(|o| is_possible(target,
-                                         o(total, series[0]),
-                                         &series[1 ..],
-                                         operators))
+    operators.iter().any(|o| o(total, Checked(series[0])).is_some_and(|t|
+        is_possible(target, t, &series[1 ..], operators)))
 }
 
 mod part_1 {
@@ -44,7 +48,7 @@ mod part_1 {
 
     fn get_result(input: &str) -> usize {
 
-        let operators = [|a, b| a + b, |a, b| a * b];
+        let operators: [Operator; 2] = [Checked::try_add, Checked::try_mul];
 
         super::get_result(input, &operators)
     }
@@ -60,16 +64,18 @@ mod part_2 {
 
     use super::*;
 
-    fn prefix(a: usize, b: usize) -> usize {
+    fn prefix(a: Checked<usize>, b: Checked<usize>) -> Option<Checked<usize>> {
+
+        let a = a.try_mul(Checked(10))?;
 
-        match b { 0 ..= 9 => a * 10, _ => prefix(a * 10, b / 10) }
+        match b.get() { 0 ..= 9 => Some(a), _ => prefix(a, b / Checked(10)) }
     }
 
     fn get_result(input: &str) -> usize {
 
-        let operators = [|a, b| a + b,
-                         |a, b| a * b,
-                         |a, b| prefix(a, b) + b];
+        let operators: [Operator; 3] = [Checked::try_add,
+                                        Checked::try_mul,
+                                        |a, b| prefix(a, b)?.try_add(b)];
 
         super::get_result(input, &operators)
     }
@@ -14,23 +14,14 @@ const EXAMPLE: &str = "............\n\
                        ............";
 mod grid {
 
-    #[derive(Clone, Copy, PartialEq, Eq)]
-    pub struct Offset { pub x: isize, pub y: isize } // Can be used as a coord
+    use super::super::vector::Vec2;
+
+    pub type Offset = Vec2<isize>; // Can be used as a coord
 
     pub struct Grid<T> { cells: Vec<Vec<T>> } // Can be jagged
 
     pub struct GridIterator<'a, T> { grid: &'a Grid<T>, offset: Offset }
 
-    impl std::ops::Sub<Offset> for Offset {
-
-        type Output = Self;
-
-        fn sub(self, offset: Self) -> Self {
-
-            Self { x: self.x - offset.x, y: self.y - offset.y }
-        }
-    }
-   
     impl<T> Grid<T> {
         
         pub fn parse(text: &str, parse_char: impl Fn(char) -> Result<T, String>)
@@ -58,7 +49,7 @@ mod grid {
                                              .and_then(|v| v.get_mut(x)))
         }
         
-        pub fn iter(&self) -> GridIterator<T> {
+        pub fn iter(&self) -> GridIterator<'_, T> {
 
             GridIterator { grid: self, offset: Offset { x: -1, y: 0 } }
         }
@@ -146,7 +137,7 @@ mod part_2 {
 
                 *c = true;
 
-                start = start - diff;
+                start -= diff;
             }
         })
     }
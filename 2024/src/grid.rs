@@ -0,0 +1,399 @@
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction { Up, Down, Left, Right }
+
+use Direction::*;
+
+use std::collections::VecDeque;
+
+use super::vector::Vec2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Turn { Left, Right }
+
+impl Direction {
+
+    pub const ALL: [Direction; 4] = [Up, Down, Left, Right];
+
+    pub const ARROWS:  [char; 4] = ['^', 'v', '<', '>']; // In ALL order
+    pub const LETTERS: [char; 4] = ['U', 'D', 'L', 'R'];
+    pub const COMPASS: [char; 4] = ['N', 'S', 'W', 'E'];
+    pub const DIGITS:  [char; 4] = ['3', '1', '2', '0'];
+
+    pub fn parse(char: char, char_set: [char; 4]) -> Option<Direction> {
+
+        char_set.iter().position(|&c| c == char).map(Self::from_index)
+    }
+
+    pub fn to_char(self, char_set: [char; 4]) -> char {
+
+        char_set[self.to_index()]
+    }
+
+    pub fn turned(self, turn: Turn) -> Direction {
+
+        let right_turned = match self { Up    => Right,
+                                        Right => Down,
+                                        Down  => Left,
+                                        Left  => Up };
+
+        match turn { Turn::Right => right_turned,
+                     Turn::Left => !right_turned }
+    }
+
+    pub fn to_index(self) -> usize {
+
+        match self { Up     => 0,
+                     Down   => 1,
+                     Left   => 2,
+                     Right  => 3 }
+    }
+
+    pub fn from_index(index: usize) -> Direction { Self::ALL[index] }
+
+    pub fn to_offset(self) -> (isize, isize) {
+
+        match self { Up    => ( 0, -1),
+                     Down  => ( 0,  1),
+                     Left  => (-1,  0),
+                     Right => ( 1,  0) }
+    }
+}
+
+impl std::fmt::Display for Direction {
+
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        
+        std::fmt::Debug::fmt(self, formatter)
+    }
+}
+
+impl std::ops::Not for Direction {
+
+    type Output = Direction;
+
+    fn not(self) -> Direction {
+        
+        match self { Up    => Down,
+                     Down  => Up,
+                     Left  => Right,
+                     Right => Left }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 { North, NorthEast, East, SouthEast,
+                      South, SouthWest, West, NorthWest }
+
+impl Direction8 {
+
+    pub const ALL: [Direction8; 8] = { use Direction8::*;
+                                       [North, NorthEast, East, SouthEast,
+                                        South, SouthWest, West, NorthWest] };
+
+    pub fn turned(self, turn: Turn) -> Direction8 { // By 45 degrees
+
+        Self::ALL[match turn { Turn::Right => self.to_index() + 1,
+                               Turn::Left  => self.to_index() + 7 } % 8]
+    }
+
+    pub fn to_index(self) -> usize { self as usize } // Clockwise from North
+
+    pub fn from_index(index: usize) -> Direction8 { Self::ALL[index] }
+
+    pub fn to_offset(self) -> (isize, isize) {
+
+        use Direction8::*;
+
+        match self { North     => ( 0, -1),
+                     NorthEast => ( 1, -1),
+                     East      => ( 1,  0),
+                     SouthEast => ( 1,  1),
+                     South     => ( 0,  1),
+                     SouthWest => (-1,  1),
+                     West      => (-1,  0),
+                     NorthWest => (-1, -1) }
+    }
+}
+
+impl std::ops::Not for Direction8 {
+
+    type Output = Direction8;
+
+    fn not(self) -> Direction8 { Self::ALL[(self.to_index() + 4) % 8] }
+}
+
+impl From<Direction> for Direction8 {
+
+    fn from(direction: Direction) -> Self {
+
+        match direction { Up    => Direction8::North,
+                          Down  => Direction8::South,
+                          Left  => Direction8::West,
+                          Right => Direction8::East }
+    }
+}
+
+pub type Offset = Vec2<isize>; // Can be used as a coord
+
+pub struct Grid<T> { cells: Vec<Vec<T>> } // Can be jagged
+
+pub struct GridIterator<'a, T> { grid: &'a Grid<T>, offset: Offset }
+
+pub trait GridCell: Sized {
+
+    fn from_char(char: char) -> Result<Self, String>;
+
+    fn to_char(&self) -> char;
+}
+
+impl GridCell for char {
+
+    fn from_char(char: char) -> Result<Self, String> { Ok(char) }
+
+    fn to_char(&self) -> char { *self }
+}
+
+impl From<Direction> for Offset {
+
+    fn from(direction: Direction) -> Self {
+
+        let (x, y) = direction.to_offset();
+
+        Self { x, y }
+    }
+}
+
+impl From<Direction8> for Offset {
+
+    fn from(direction: Direction8) -> Self {
+
+        let (x, y) = direction.to_offset();
+
+        Self { x, y }
+    }
+}
+
+impl std::ops::Add<Direction> for Offset {
+
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self { self + Self::from(direction) }
+}
+
+impl std::ops::Sub<Direction> for Offset {
+
+    type Output = Self;
+
+    fn sub(self, direction: Direction) -> Self { self - Self::from(direction) }
+}
+
+impl<T> Grid<T> {
+
+    pub fn new(end: Offset, value: T)
+        -> Result<Self, Box<dyn std::error::Error>>
+        where T: Clone {
+
+        Ok(Self {
+
+            cells: vec![vec![value; usize::try_from(end.x + 1)?];
+                        usize::try_from(end.y + 1)?]
+        })
+    }
+
+    pub fn parse(text: &str, parse_char: impl Fn(char) -> Result<T, String>)
+        -> Result<Self, String> {
+
+        Ok(Self { cells: text.split('\n')
+                             .map(|l| l.chars().map(&parse_char).collect())
+                             .collect::<Result<_, _>>()? })
+    }
+
+    pub fn parse_cells(text: &str) -> Result<Self, String> where T: GridCell {
+
+        Self::parse(text, T::from_char)
+    }
+
+    pub fn get(&self, offset: Offset) -> Option<&T> {
+
+        usize::try_from(offset.x)
+            .ok()
+            .zip(usize::try_from(offset.y).ok())
+            .and_then(|(x, y)| self.cells.get(y).and_then(|v| v.get(x)))
+    }
+
+    pub fn get_mut(&mut self, offset: Offset) -> Option<&mut T> {
+
+        usize::try_from(offset.x)
+            .ok()
+            .zip(usize::try_from(offset.y).ok())
+            .and_then(|(x, y)| self.cells.get_mut(y)
+                                         .and_then(|v| v.get_mut(x)))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+
+        Grid::<U> { cells: self.cells.iter()
+                                     .map(|v| v.iter().map(&f).collect())
+                                     .collect() }
+    }
+
+    pub fn iter(&self) -> GridIterator<'_, T> {
+
+        GridIterator { grid: self, offset: Offset { x: -1, y: 0 } }
+    }
+
+    pub fn render(&self, to_char: impl Fn(Offset, &T) -> char) -> String {
+
+        let render_row = |(y, v): (usize, &Vec<T>)|
+            v.iter()
+             .enumerate()
+             .map(|(x, t)| to_char(Offset { x: x as isize, y: y as isize }, t))
+             .collect::<String>();
+
+        self.cells.iter()
+                  .enumerate()
+                  .map(render_row)
+                  .collect::<Vec<_>>()
+                  .join("\n")
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Source { pub offset: Offset, pub cost: u32 }
+
+impl From<Offset> for Source {
+
+    fn from(offset: Offset) -> Self { Self { offset, cost: 0 } }
+}
+
+impl From<(Offset, u32)> for Source {
+
+    fn from((offset, cost): (Offset, u32)) -> Self { Self { offset, cost } }
+}
+
+pub fn distance_field<T, S: Into<Source>>(grid: &Grid<T>,
+                                          sources: impl IntoIterator<Item = S>,
+                                          passable: impl Fn(&T) -> bool)
+    -> Grid<Option<u32>> {
+
+    distance_field_with_parents(grid, sources, passable).0
+}
+
+// Parents are the direction stepped in to reach each cell, None for sources
+pub fn distance_field_with_parents<T, S: Into<Source>>(
+    grid: &Grid<T>,
+    sources: impl IntoIterator<Item = S>,
+    passable: impl Fn(&T) -> bool)
+    -> (Grid<Option<u32>>, Grid<Option<Direction>>) {
+
+    let mut distances = grid.map(|_| None);
+    let mut parents = grid.map(|_| None);
+
+    let mut sources = sources.into_iter().map(Into::into).collect::<Vec<_>>();
+
+    sources.sort_by_key(|s| s.cost);
+
+    // Both queues stay sorted by cost, so merging them visits cells in order
+    type Visit = (Offset, u32, Option<Direction>);
+
+    let mut seeds = sources.into_iter()
+                           .map(|s| (s.offset, s.cost, None))
+                           .collect::<VecDeque<Visit>>();
+
+    let mut frontier = VecDeque::<Visit>::new();
+
+    loop {
+
+        let from_seeds = match (seeds.front(), frontier.front()) {
+            (Some(s), Some(f)) => s.1 <= f.1,
+            (seed, _) => seed.is_some()
+        };
+
+        let Some((offset, cost, parent)) =
+            (if from_seeds { seeds.pop_front() } else { frontier.pop_front() })
+            else { break };
+
+        if !grid.get(offset).is_some_and(&passable) { continue; }
+
+        let distance = distances.get_mut(offset).unwrap();
+
+        if distance.is_some() { continue; }
+
+        *distance = Some(cost);
+
+        *parents.get_mut(offset).unwrap() = parent;
+
+        frontier.extend(Direction::ALL
+                            .map(|d| (offset + d, cost + 1, Some(d))));
+    }
+
+    (distances, parents)
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+
+    fn from(cells: Vec<Vec<T>>) -> Self { Self { cells } }
+}
+
+impl<T: GridCell> std::fmt::Display for Grid<T> {
+
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+
+        formatter.write_str(&self.render(|_, v| v.to_char()))
+    }
+}
+
+impl<'a, T> Iterator for GridIterator<'a, T> {
+
+    type Item = (Offset, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        if self.offset.y as usize >= self.grid.cells.len() { return None; }
+
+        self.offset.x += 1;
+
+        match self.grid.get(self.offset) {
+
+            Some(v) => Some((self.offset, v)),
+
+            _ => { self.offset = Offset { x: -1, y: self.offset.y + 1 };
+                   self.next() }
+        }
+    }
+}
+
+#[test]
+fn test_distance_field() {
+
+    let grid = Grid::<char>::parse_cells("...#.\n\
+                                          .#...\n\
+                                          ...#.").unwrap();
+
+    let origin = Offset { x: 0, y: 0 };
+
+    let distances = distance_field(&grid, [origin], |&c| c == '.');
+
+    let render = |distances: &Grid<Option<u32>>|
+        distances.render(|_, d| d.map_or('#', |d| (b'0' + d as u8) as char));
+
+    assert_eq!(render(&distances), "012#6\n\
+                                    1#345\n\
+                                    234#6");
+
+    let sources = [(origin, 0), (Offset { x: 4, y: 0 }, 1)];
+
+    let distances = distance_field(&grid, sources, |&c| c == '.');
+
+    assert_eq!(render(&distances), "012#1\n\
+                                    1#332\n\
+                                    234#3");
+
+    let (_, parents) =
+        distance_field_with_parents(&grid, [origin], |&c| c == '.');
+
+    assert_eq!(parents.get(origin), Some(&None));
+
+    assert_eq!(parents.get(Offset { x: 4, y: 0 }), Some(&Some(Up)));
+}
@@ -0,0 +1,109 @@
+
+use super::rational::Rational;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solution {
+
+    Unique(Vec<Rational>),
+
+    None,
+
+    // Every particular + sum of t_i * directions[i]
+    Infinite { particular: Vec<Rational>, directions: Vec<Vec<Rational>> }
+}
+
+// Gaussian elimination to reduced row echelon form, for a.x = b
+pub fn solve(mut a: Vec<Vec<Rational>>, mut b: Vec<Rational>) -> Solution {
+
+    let columns = a.first().map_or(0, |r| r.len());
+
+    let mut pivots = Vec::new(); // Column of each pivot row in turn
+
+    for column in 0 .. columns {
+
+        let row = pivots.len();
+
+        let Some(pivot) = (row .. a.len()).find(|&r| !a[r][column].is_zero())
+            else { continue };
+
+        a.swap(row, pivot);
+        b.swap(row, pivot);
+
+        let scale = a[row][column].recip();
+
+        for value in a[row].iter_mut() { *value = *value * scale; }
+
+        b[row] = b[row] * scale;
+
+        let pivot_row = a[row].clone();
+
+        for other in (0 .. a.len()).filter(|&r| r != row) {
+
+            let factor = a[other][column];
+
+            if factor.is_zero() { continue; }
+
+            for (value, &p) in a[other].iter_mut().zip(&pivot_row) {
+
+                *value = *value - factor * p;
+            }
+
+            b[other] = b[other] - factor * b[row];
+        }
+
+        pivots.push(column);
+    }
+
+    if b[pivots.len() ..].iter().any(|v| !v.is_zero()) {
+
+        return Solution::None;
+    }
+
+    let mut particular = vec![Rational::ZERO; columns];
+
+    for (row, &column) in pivots.iter().enumerate() {
+
+        particular[column] = b[row];
+    }
+
+    if pivots.len() == columns { return Solution::Unique(particular); }
+
+    let directions = (0 .. columns)
+        .filter(|c| !pivots.contains(c))
+        .map(|free| {
+            let mut direction = vec![Rational::ZERO; columns];
+            direction[free] = Rational::ONE;
+            for (row, &column) in pivots.iter().enumerate() {
+                direction[column] = -a[row][free];
+            }
+            direction
+        })
+        .collect();
+
+    Solution::Infinite { particular, directions }
+}
+
+#[test]
+fn test_solve() {
+
+    let matrix = |rows: &[&[i64]]| rows.iter()
+                                       .map(|r| r.iter()
+                                                 .map(|&v| Rational::from(v))
+                                                 .collect())
+                                       .collect::<Vec<_>>();
+
+    let vector = |values: &[i64]| values.iter()
+                                        .map(|&v| Rational::from(v))
+                                        .collect::<Vec<_>>();
+
+    assert_eq!(solve(matrix(&[&[2, 1, -1], &[-3, -1, 2], &[-2, 1, 2]]),
+                     vector(&[8, -11, -3])),
+               Solution::Unique(vector(&[2, 3, -1])));
+
+    assert_eq!(solve(matrix(&[&[1, 2], &[2, 4]]), vector(&[3, 7])),
+               Solution::None);
+
+    assert_eq!(solve(matrix(&[&[1, 2], &[2, 4]]), vector(&[3, 6])),
+               Solution::Infinite { particular: vector(&[3, 0]),
+                                    directions: vec![vector(&[-2, 1])] });
+}
@@ -42,4 +42,26 @@ mod day_19;
 
 mod day_20;
 
+mod cheats;
+
+mod checked;
+
+mod connectivity;
+
+mod graph;
+
+mod grid;
+
+mod linear;
+
+mod memo;
+
+mod number;
+
+mod rational;
+
+mod search;
+
+mod vector;
+
 fn main() {}
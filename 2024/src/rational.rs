@@ -0,0 +1,144 @@
+
+use super::{ checked::Checked, number::extended_gcd };
+
+use std::{ cmp::Ordering, ops::{ Add, Div, Mul, Neg, Sub } };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational { numerator: i128, denominator: i128 } // In lowest terms
+
+impl Rational {
+
+    pub const ZERO: Rational = Rational { numerator: 0, denominator: 1 };
+    pub const ONE:  Rational = Rational { numerator: 1, denominator: 1 };
+
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+
+        assert!(denominator != 0, "Rational with zero denominator");
+
+        let (g, _, _) = extended_gcd(numerator, denominator);
+
+        let sign = denominator.signum();
+
+        Self { numerator: sign * numerator / g,
+               denominator: sign * denominator / g }
+    }
+
+    pub fn numerator(self) -> i128 { self.numerator }
+
+    pub fn denominator(self) -> i128 { self.denominator }
+
+    pub fn is_zero(self) -> bool { self.numerator == 0 }
+
+    pub fn to_integer(self) -> Option<i128> {
+
+        (self.denominator == 1).then_some(self.numerator)
+    }
+
+    pub fn recip(self) -> Self { Self::new(self.denominator, self.numerator) }
+}
+
+impl From<i128> for Rational {
+
+    fn from(value: i128) -> Self { Self { numerator: value, denominator: 1 } }
+}
+
+impl From<i64> for Rational {
+
+    fn from(value: i64) -> Self { Self::from(value as i128) }
+}
+
+fn product(a: i128, b: i128) -> i128 { (Checked(a) * Checked(b)).get() }
+
+impl Add for Rational {
+
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+
+        Self::new((Checked(product(self.numerator, other.denominator))
+                   + Checked(product(other.numerator, self.denominator))).get(),
+                  product(self.denominator, other.denominator))
+    }
+}
+
+impl Sub for Rational {
+
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self { self + -other }
+}
+
+impl Mul for Rational {
+
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+
+        Self::new(product(self.numerator, other.numerator),
+                  product(self.denominator, other.denominator))
+    }
+}
+
+impl Div for Rational {
+
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+
+        Self::new(product(self.numerator, other.denominator),
+                  product(self.denominator, other.numerator))
+    }
+}
+
+impl Neg for Rational {
+
+    type Output = Self;
+
+    fn neg(self) -> Self { Self { numerator: -self.numerator, ..self } }
+}
+
+impl PartialOrd for Rational {
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+
+        product(self.numerator, other.denominator)
+            .cmp(&product(other.numerator, self.denominator))
+    }
+}
+
+impl std::fmt::Display for Rational {
+
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+
+        match self.denominator {
+            1 => write!(formatter, "{}", self.numerator),
+            d => write!(formatter, "{}/{}", self.numerator, d)
+        }
+    }
+}
+
+#[test]
+fn test_rational() {
+
+    let (half, third) = (Rational::new(1, 2), Rational::new(-2, -6));
+
+    assert_eq!(half + third, Rational::new(5, 6));
+
+    assert_eq!(half - third, Rational::new(1, 6));
+
+    assert_eq!(half * third / Rational::new(1, 6), Rational::ONE);
+
+    assert_eq!(Rational::new(4, -6).to_string(), "-2/3");
+
+    assert_eq!(Rational::new(8, 4).to_integer(), Some(2));
+
+    assert!(third < half && -half < Rational::ZERO);
+}
@@ -0,0 +1,265 @@
+
+use std::{ cmp::Ordering,
+           ops::{ Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign } };
+
+pub trait Integer: Copy + Ord + Default
+                   + Add<Output = Self> + Sub<Output = Self>
+                   + Mul<Output = Self> + Div<Output = Self>
+                   + Rem<Output = Self> + Neg<Output = Self> {
+
+    type Unsigned: Copy + Ord + Add<Output = Self::Unsigned>;
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+
+    fn rem_euclid(self, modulus: Self) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty => $unsigned:ty),*) => { $(
+        impl Integer for $t {
+
+            type Unsigned = $unsigned;
+
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs_diff(self, other: Self) -> $unsigned {
+                <$t>::abs_diff(self, other)
+            }
+
+            fn rem_euclid(self, modulus: Self) -> Self {
+                <$t>::rem_euclid(self, modulus)
+            }
+        }
+    )* }
+}
+
+integer!(i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+// With y increasing downwards, as in a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Vec2<T> { pub x: T, pub y: T }
+
+impl<T: Integer> Vec2<T> {
+
+    pub fn new(x: T, y: T) -> Self { Self { x, y } }
+
+    pub fn manhattan(self, other: Self) -> T::Unsigned {
+
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T::Unsigned {
+
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    pub fn turn_right(self) -> Self { Self { x: -self.y, y: self.x } }
+
+    pub fn turn_left(self) -> Self { Self { x: self.y, y: -self.x } }
+
+    pub fn signum(self) -> Self {
+
+        let signum = |v: T| match v.cmp(&T::ZERO) {
+            Ordering::Less    => -T::ONE,
+            Ordering::Equal   => T::ZERO,
+            Ordering::Greater => T::ONE
+        };
+
+        Self { x: signum(self.x), y: signum(self.y) }
+    }
+
+    pub fn dot(self, other: Self) -> T { self.x * other.x + self.y * other.y }
+
+    pub fn cross(self, other: Self) -> T { // Positive turning clockwise
+
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn rem_euclid(self, size: Self) -> Self { // Wraps into 0 .. size
+
+        Self { x: self.x.rem_euclid(size.x), y: self.y.rem_euclid(size.y) }
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+
+    fn from((x, y): (T, T)) -> Self { Self { x, y } }
+}
+
+impl<T: Integer> Add for Vec2<T> {
+
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+
+        Self { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl<T: Integer> Sub for Vec2<T> {
+
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+
+        Self { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl<T: Integer> AddAssign for Vec2<T> {
+
+    fn add_assign(&mut self, other: Self) { *self = *self + other; }
+}
+
+impl<T: Integer> SubAssign for Vec2<T> {
+
+    fn sub_assign(&mut self, other: Self) { *self = *self - other; }
+}
+
+impl<T: Integer> Neg for Vec2<T> {
+
+    type Output = Self;
+
+    fn neg(self) -> Self { Self { x: -self.x, y: -self.y } }
+}
+
+impl<T: Integer> Mul<T> for Vec2<T> {
+
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+
+        Self { x: self.x * scalar, y: self.y * scalar }
+    }
+}
+
+impl<T: Integer> Div<T> for Vec2<T> {
+
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self {
+
+        Self { x: self.x / scalar, y: self.y / scalar }
+    }
+}
+
+impl<T: Integer> Rem<T> for Vec2<T> {
+
+    type Output = Self;
+
+    fn rem(self, scalar: T) -> Self {
+
+        Self { x: self.x % scalar, y: self.y % scalar }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect<T> { pub from: Vec2<T>, pub to: Vec2<T> } // Half open
+
+impl<T: Integer> Rect<T> {
+
+    pub fn new(from: Vec2<T>, to: Vec2<T>) -> Self { Self { from, to } }
+
+    pub fn size(&self) -> Vec2<T> { self.to - self.from }
+
+    pub fn is_empty(&self) -> bool {
+
+        self.from.x >= self.to.x || self.from.y >= self.to.y
+    }
+
+    pub fn contains(&self, point: Vec2<T>) -> bool {
+
+           point.x >= self.from.x && point.y >= self.from.y
+        && point.x < self.to.x && point.y < self.to.y
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+
+        let rect = Self {
+            from: Vec2 { x: self.from.x.max(other.from.x),
+                         y: self.from.y.max(other.from.y) },
+            to:   Vec2 { x: self.to.x.min(other.to.x),
+                         y: self.to.y.min(other.to.y) }
+        };
+
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    // Top left, top right, bottom left, bottom right, leaving out the
+    // middle row and column when the size is odd
+    pub fn quadrants(&self) -> [Self; 4] {
+
+        let two = T::ONE + T::ONE;
+
+        let half = self.size() / two;
+
+        let (low, high) = (self.from + half, self.to - half);
+
+        let corner = |x: bool, y: bool| Self {
+            from: Vec2 { x: if x { high.x } else { self.from.x },
+                         y: if y { high.y } else { self.from.y } },
+            to:   Vec2 { x: if x { self.to.x } else { low.x },
+                         y: if y { self.to.y } else { low.y } }
+        };
+
+        [corner(false, false), corner(true, false),
+         corner(false, true), corner(true, true)]
+    }
+}
+
+#[test]
+fn test_vec2() {
+
+    let (a, b) = (Vec2::new(3, -4), Vec2::new(-1, 2));
+
+    assert_eq!((a + b, a - b, -a), ((2, -2).into(), (4, -6).into(),
+                                     (-3, 4).into()));
+
+    assert_eq!((a * 2, a / 2, a % 2), ((6, -8).into(), (1, -2).into(),
+                                       (1, 0).into()));
+
+    assert_eq!(a.rem_euclid(Vec2::new(5, 3)), Vec2::new(3, 2));
+
+    assert_eq!((a.manhattan(b), a.chebyshev(b)), (10u32, 6u32));
+
+    let up = Vec2::new(0, -1);
+
+    assert_eq!(up.turn_right(), Vec2::new(1, 0));
+
+    assert_eq!(up.turn_left(), Vec2::new(-1, 0));
+
+    assert_eq!(up.turn_right().turn_right().turn_right(), up.turn_left());
+
+    assert_eq!((a.signum(), Vec2::new(0, 7i64).signum()),
+               (Vec2::new(1, -1), Vec2::new(0, 1)));
+
+    assert_eq!((a.dot(b), a.cross(b)), (-11, 2));
+
+    assert_eq!(up.cross(up.turn_right()), 1);
+}
+
+#[test]
+fn test_rect() {
+
+    let room = Rect::new(Vec2::new(0, 0), Vec2::new(11, 7));
+
+    let [top_left, _, _, bottom_right] = room.quadrants();
+
+    assert_eq!(top_left, Rect::new((0, 0).into(), (5, 3).into()));
+
+    assert_eq!(bottom_right, Rect::new((6, 4).into(), (11, 7).into()));
+
+    assert!(room.contains((10, 6).into()) && !room.contains((11, 0).into()));
+
+    let even = Rect::new(Vec2::new(0, 0), Vec2::new(4, 2)).quadrants();
+
+    assert_eq!(even[3], Rect::new((2, 1).into(), (4, 2).into()));
+
+    assert_eq!(room.intersection(&bottom_right), Some(bottom_right));
+
+    assert_eq!(top_left.intersection(&bottom_right), None);
+}